@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sol::{day01::Day01, solution::Solution};
+
+fuzz_target!(|input_data: &str| {
+    // malformed inputs have to be rejected with an error instead of a panic
+    let _ = Day01::parse(input_data);
+});
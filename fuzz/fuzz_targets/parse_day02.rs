@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sol::{day02::Day02, solution::Solution};
+
+fuzz_target!(|input_data: &str| {
+    // malformed inputs have to be rejected with an error instead of a panic
+    let _ = Day02::parse(input_data);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sol::{day03::Day03, solution::Solution};
+
+fuzz_target!(|input_data: &str| {
+    // malformed inputs have to be rejected with an error instead of a panic
+    let _ = Day03::parse(input_data);
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sol::{day05::Day05, solution::Solution};
+
+fuzz_target!(|input_data: &str| {
+    // malformed inputs have to be rejected with an error instead of a panic
+    let _ = Day05::parse(input_data);
+});
@@ -0,0 +1,138 @@
+use std::{collections::HashMap, io};
+
+use crate::{
+    params::Params,
+    solution::{Answer, PartReport},
+};
+
+/// Expected answers of a day, stored next to its inputs in `dayNN/answers.toml`.
+///
+/// The file uses a small subset of TOML: one table per input file containing the answers of the
+/// parts, e.g.
+///
+/// ```toml
+/// # answers of the example from the puzzle description
+/// ["example.txt"]
+/// part1 = 3
+/// part2 = 6
+/// ```
+///
+/// If an input needs other parameters than the puzzle, they are given as `params.<name> = value`
+/// in its table.
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<String, HashMap<u8, Answer>>,
+    params: HashMap<String, Params>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckResult {
+    Pass,
+    Fail {
+        expected: Answer,
+    },
+    /// There is no expected answer for this input file and part.
+    Unknown,
+}
+
+impl ExpectedAnswers {
+    pub fn path(day: u8) -> String {
+        format!("day{day:02}/answers.toml")
+    }
+
+    /// Loads the expected answers of `day`. A missing file is treated as a file without answers.
+    pub fn load(day: u8) -> io::Result<Option<Self>> {
+        match std::fs::read_to_string(Self::path(day)) {
+            Ok(data) => Ok(Self::parse(&data)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Some(Self::default())),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(data: &str) -> Option<Self> {
+        let mut answers: HashMap<String, HashMap<u8, Answer>> = HashMap::new();
+        let mut params: HashMap<String, Params> = HashMap::new();
+        let mut current_table = None;
+
+        for line in data.lines() {
+            let line = match line.split_once("#") {
+                Some((content, _comment)) => content,
+                None => line,
+            }
+            .trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix("[") {
+                let table = table.strip_suffix("]")?.trim();
+                let table = match table.strip_prefix("\"") {
+                    Some(quoted) => quoted.strip_suffix("\"")?,
+                    None => table,
+                };
+                answers.entry(table.to_string()).or_default();
+                current_table = Some(table.to_string());
+                continue;
+            }
+
+            let (key, value) = line.split_once("=")?;
+            let key = key.trim();
+            let value = value.trim();
+            let value = match value.strip_prefix("\"") {
+                Some(quoted) => quoted.strip_suffix("\"")?,
+                None => value,
+            };
+            let table = current_table.as_ref()?;
+
+            if let Some(name) = key.strip_prefix("params.") {
+                params
+                    .entry(table.clone())
+                    .or_default()
+                    .set(name.trim(), value);
+                continue;
+            }
+
+            let part = key.strip_prefix("part")?.parse().ok()?;
+            let answer = value.parse().ok()?;
+            answers.get_mut(table)?.insert(part, answer);
+        }
+
+        Some(Self { answers, params })
+    }
+
+    /// All input files with expected answers, sorted by name.
+    pub fn input_files(&self) -> Vec<&str> {
+        let mut input_files = self.answers.keys().map(String::as_str).collect::<Vec<_>>();
+        input_files.sort_unstable();
+        input_files
+    }
+
+    /// The parts with an expected answer for `input_file`, sorted by part.
+    pub fn parts(&self, input_file: &str) -> Vec<u8> {
+        let mut parts = self
+            .answers
+            .get(input_file)
+            .map(|answers| answers.keys().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        parts.sort_unstable();
+        parts
+    }
+
+    /// The parameters `input_file` has to be solved with.
+    pub fn params(&self, input_file: &str) -> Params {
+        self.params.get(input_file).cloned().unwrap_or_default()
+    }
+
+    pub fn get(&self, input_file: &str, part: u8) -> Option<Answer> {
+        self.answers.get(input_file)?.get(&part).copied()
+    }
+
+    pub fn check(&self, input_file: &str, report: &PartReport) -> CheckResult {
+        match self.get(input_file, report.part) {
+            Some(expected) if expected == report.answer => CheckResult::Pass,
+            Some(expected) => CheckResult::Fail { expected },
+            None => CheckResult::Unknown,
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    hint::black_box,
+    io,
+    time::{Duration, Instant},
+};
+
+use crate::report::print_table;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// Number of runs before the measurement starts.
+    pub warmup: usize,
+    /// Number of measured runs, has to be at least one.
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort_unstable();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        let mean_secs =
+            samples.iter().map(Duration::as_secs_f64).sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` `options.warmup` times and then measures `options.iterations` runs of it.
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..options.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+#[derive(Debug, Clone)]
+pub struct PartBench {
+    pub part: u8,
+    pub variant: &'static str,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+/// Task number used for parsing in [`DayBench::entries`] and in baselines.
+pub const PARSE_TASK: u8 = 0;
+
+impl DayBench {
+    /// The statistics of parsing and all variants as `(task, variant, stats)`, where parsing is
+    /// reported as task [`PARSE_TASK`] with the variant `"parse"`.
+    pub fn entries(&self) -> impl Iterator<Item = (u8, &'static str, &Stats)> {
+        std::iter::once((PARSE_TASK, "parse", &self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|part| (part.part, part.variant, &part.stats)),
+        )
+    }
+}
+
+/// Medians of earlier benchmark runs, stored as CSV with the columns
+/// `day,input,task,variant,median_ns`.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, String, u8, String), Duration>,
+}
+
+impl Baseline {
+    pub const DEFAULT_PATH: &str = "bench_baseline.csv";
+
+    /// Loads the baseline from `path`. A missing file is treated as an empty baseline.
+    pub fn load(path: &str) -> io::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => Ok(Self::parse(&data)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Some(Self::default())),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(data: &str) -> Option<Self> {
+        let mut medians = BTreeMap::new();
+        for record in parse_csv(data)?.into_iter().skip(1) {
+            let [day, input_file, task, variant, median] = <[String; 5]>::try_from(record).ok()?;
+            let median = Duration::from_nanos(median.parse().ok()?);
+            medians.insert(
+                (day.parse().ok()?, input_file, task.parse().ok()?, variant),
+                median,
+            );
+        }
+        Some(Self { medians })
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let mut data = String::from("day,input,task,variant,median_ns\n");
+        for ((day, input_file, task, variant), median) in &self.medians {
+            data.push_str(&format!(
+                "{day},{},{task},{},{}\n",
+                csv_field(input_file),
+                csv_field(variant),
+                median.as_nanos()
+            ));
+        }
+        std::fs::write(path, data)
+    }
+
+    pub fn get(&self, day: u8, input_file: &str, task: u8, variant: &str) -> Option<Duration> {
+        self.medians
+            .get(&(day, input_file.to_string(), task, variant.to_string()))
+            .copied()
+    }
+
+    /// Replaces the medians of all benchmarked tasks with the new ones.
+    pub fn update(&mut self, input_file: &str, benches: &[DayBench]) {
+        for bench in benches {
+            for (task, variant, stats) in bench.entries() {
+                self.medians.insert(
+                    (bench.day, input_file.to_string(), task, variant.to_string()),
+                    stats.median,
+                );
+            }
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break, e.g. an input path.
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Splits CSV data into records of fields. Quoted fields can contain separators, line breaks and
+/// doubled quotes. `None` if a quote is not closed or followed by other text than a separator.
+fn parse_csv(data: &str) -> Option<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut chars = data.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                loop {
+                    match chars.next()? {
+                        '"' if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        '"' => break,
+                        c => field.push(c),
+                    }
+                }
+                if !matches!(chars.peek(), None | Some(',' | '\n' | '\r')) {
+                    return None;
+                }
+            }
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Some(records)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}µs", duration.as_secs_f64() * 1e6)
+}
+
+/// Prints the benchmark results and compares the medians against the baseline. Tasks that got
+/// slower by more than `threshold` (e.g. `0.1` for 10%) are flagged and their number is returned.
+pub fn print_benches(
+    benches: &[DayBench],
+    baseline: &Baseline,
+    input_file: &str,
+    threshold: f64,
+) -> usize {
+    let mut num_regressions = 0;
+    let rows = benches
+        .iter()
+        .flat_map(|bench| {
+            bench
+                .entries()
+                .map(move |(task, variant, stats)| (bench.day, task, variant, stats))
+        })
+        .map(|(day, task, variant, stats)| {
+            let old_median = baseline.get(day, input_file, task, variant);
+            let change = match old_median {
+                Some(old_median) if !old_median.is_zero() => {
+                    let change = stats.median.as_secs_f64() / old_median.as_secs_f64() - 1.0;
+                    if change > threshold {
+                        num_regressions += 1;
+                        format!("{:+.1}% SLOWER", change * 100.0)
+                    } else {
+                        format!("{:+.1}%", change * 100.0)
+                    }
+                }
+                _ => String::from("-"),
+            };
+
+            [
+                day.to_string(),
+                if task == PARSE_TASK {
+                    String::from("-")
+                } else {
+                    task.to_string()
+                },
+                variant.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.stddev),
+                old_median.map_or_else(|| String::from("-"), format_duration),
+                change,
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    print_table(
+        [
+            "Day", "Task", "Variant", "Min", "Median", "Mean", "Stddev", "Baseline", "Change",
+        ],
+        &rows,
+    );
+
+    num_regressions
+}
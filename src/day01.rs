@@ -1,26 +1,25 @@
-use std::time::Instant;
+use std::fmt::Display;
+
+use crate::{
+    generate::Rng,
+    params::{Param, ParamKind, Params},
+    parse::ParseError,
+    solution::{Answer, DEFAULT_VARIANT, Solution, SolveError, Variant},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
+pub enum Direction {
     Left,
     Right,
 }
 
 impl Direction {
-    #[allow(unused)]
     fn to_char(&self) -> char {
         match self {
             Self::Left => 'L',
             Self::Right => 'R',
         }
     }
-
-    fn to_factor(&self) -> i64 {
-        match self {
-            Self::Left => -1,
-            Self::Right => 1,
-        }
-    }
 }
 
 impl TryFrom<char> for Direction {
@@ -35,114 +34,683 @@ impl TryFrom<char> for Direction {
     }
 }
 
-#[derive(Debug)]
-struct Rotation {
-    dir: Direction,
-    distance: u64,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Turns the dial by `distance` steps.
+    Turn { dir: Direction, distance: u64 },
+    /// Sets the dial to `position` without turning it, positions beyond the dial are wrapped onto
+    /// it.
+    Absolute { position: u64 },
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Turn { dir, distance } => write!(f, "{}{distance}", dir.to_char()),
+            Self::Absolute { position } => write!(f, "={position}"),
+        }
+    }
+}
+
+/// Repeat blocks are expanded while parsing, so the rotations of all blocks of an input are
+/// limited to keep its memory bounded. The rotations of nested blocks count once per level.
+pub const MAX_REPEATED_ROTATIONS: usize = 1_000_000;
+
+/// Repeat blocks are parsed recursively, so their nesting is limited to keep the stack bounded.
+pub const MAX_REPEAT_DEPTH: usize = 100;
+
+/// Parses one rotation per line. A line can also contain several rotations separated by
+/// whitespace, repeat blocks like `3x(R10 L5)`, which may be nested and are expanded right away,
+/// and a `#` comment.
+pub fn parse_input(input_data: &str) -> Result<Vec<Rotation>, ParseError> {
+    let mut rotations = Vec::new();
+    let mut repeated = 0;
+    for line in input_data.lines() {
+        let code = line.split('#').next().unwrap_or_default();
+        let rest = parse_rotations(input_data, code, &mut rotations, &mut repeated, 0)?;
+        if !rest.is_empty() {
+            return Err(ParseError::at(input_data, first_char(rest), "a rotation"));
+        }
+    }
+    Ok(rotations)
+}
+
+/// Parses rotations and repeat blocks until the end of `code` or a closing parenthesis and returns
+/// the rest of `code`. `repeated` counts the rotations of all repeat blocks so far and `depth` the
+/// repeat blocks `code` is nested in.
+fn parse_rotations<'a>(
+    input_data: &str,
+    mut code: &'a str,
+    rotations: &mut Vec<Rotation>,
+    repeated: &mut usize,
+    depth: usize,
+) -> Result<&'a str, ParseError> {
+    loop {
+        code = code.trim_start();
+        if code.is_empty() || code.starts_with(')') {
+            return Ok(code);
+        }
+
+        if code.starts_with(|c: char| c.is_ascii_digit()) {
+            let rest = parse_repeat(input_data, code, rotations, repeated, depth + 1)?;
+            code = separated(input_data, rest)?;
+            continue;
+        }
+
+        let dir_str = first_char(code);
+        let (number_str, rest) = split_number(&code[dir_str.len()..]);
+        let rotation = if dir_str == "=" {
+            let Ok(position) = number_str.parse() else {
+                return Err(ParseError::at(input_data, number_str, "a position"));
+            };
+            Rotation::Absolute { position }
+        } else {
+            let Ok(dir) = Direction::try_from(dir_str.chars().next().unwrap_or_default()) else {
+                return Err(ParseError::at(
+                    input_data,
+                    dir_str,
+                    "a direction (L, R or =) or a repeat count",
+                ));
+            };
+            let Ok(distance) = number_str.parse() else {
+                return Err(ParseError::at(input_data, number_str, "a distance"));
+            };
+            Rotation::Turn { dir, distance }
+        };
+        rotations.push(rotation);
+        code = separated(input_data, rest)?;
+    }
+}
+
+/// Checks that a rotation or repeat block is followed by whitespace, the end of its block or the
+/// end of its line, so that e.g. `R5L3` is rejected instead of read as two rotations.
+fn separated<'a>(input_data: &str, rest: &'a str) -> Result<&'a str, ParseError> {
+    if rest.is_empty() || rest.starts_with(|c: char| c.is_whitespace() || c == ')') {
+        Ok(rest)
+    } else {
+        Err(ParseError::at(
+            input_data,
+            first_char(rest),
+            "whitespace between rotations",
+        ))
+    }
+}
+
+/// Parses a repeat block like `3x(R10 L5)` at the start of `code` and returns the rest of `code`
+/// after the block.
+fn parse_repeat<'a>(
+    input_data: &str,
+    block_code: &'a str,
+    rotations: &mut Vec<Rotation>,
+    repeated: &mut usize,
+    depth: usize,
+) -> Result<&'a str, ParseError> {
+    let (count_str, code) = split_number(block_code);
+    let Some(code) = code.strip_prefix("x(") else {
+        return Err(ParseError::at(input_data, first_char(code), "\"x(\""));
+    };
+    if depth > MAX_REPEAT_DEPTH {
+        return Err(ParseError::at(
+            input_data,
+            count_str,
+            format!("repeat blocks nested at most {MAX_REPEAT_DEPTH} deep"),
+        ));
+    }
+
+    let mut block = Vec::new();
+    let code = parse_rotations(input_data, code, &mut block, repeated, depth)?;
+    let Some(rest) = code.strip_prefix(')') else {
+        return Err(ParseError::at(input_data, first_char(code), "\")\""));
+    };
+
+    let block_len = count_str
+        .parse::<usize>()
+        .ok()
+        .and_then(|count| count.checked_mul(block.len()))
+        .filter(|&block_len| block_len <= MAX_REPEATED_ROTATIONS - *repeated);
+    let Some(block_len) = block_len else {
+        // points at the whole block, so that the error shows which block of the line is too long
+        return Err(ParseError::at(
+            input_data,
+            &block_code[..block_code.len() - rest.len()],
+            format!(
+                "repeat blocks of at most {MAX_REPEATED_ROTATIONS} rotations in total, not {count_str} times {} more",
+                block.len()
+            ),
+        ));
+    };
+    *repeated += block_len;
+    rotations.extend(block.iter().cycle().take(block_len));
+    Ok(rest)
+}
+
+/// Splits the leading decimal digits off `code`.
+fn split_number(code: &str) -> (&str, &str) {
+    code.split_at(
+        code.find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(code.len()),
+    )
+}
+
+/// The first character of `code`, empty if `code` is empty.
+fn first_char(code: &str) -> &str {
+    &code[..code.chars().next().map_or(0, char::len_utf8)]
 }
 
-fn parse_input(input_data: String) -> Option<Vec<Rotation>> {
-    input_data
-        .lines()
-        .map(|line| {
-            if line.len() >= 2
-                && let Ok(dir) = line.chars().next().unwrap().try_into()
-                && let Ok(distance) = line[1..].parse()
-            {
-                Some(Rotation { dir, distance })
+/// `size` random rotations, with a few absolute moves and repeat blocks in between.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let rotation = |rng: &mut Rng| {
+        if rng.chance(0.05) {
+            Rotation::Absolute {
+                position: rng.range(0..=99),
+            }
+        } else {
+            let dir = if rng.chance(0.5) {
+                Direction::Left
+            } else {
+                Direction::Right
+            };
+            Rotation::Turn {
+                dir,
+                distance: rng.range(1..=999),
+            }
+        }
+        .to_string()
+    };
+
+    (0..size)
+        .map(|_| {
+            if rng.chance(0.05) {
+                let block = (0..rng.range(1..=3))
+                    .map(|_| rotation(rng))
+                    .collect::<Vec<_>>();
+                format!("{}x({})", rng.range(2..=5), block.join(" "))
             } else {
-                None
+                rotation(rng)
             }
         })
-        .collect()
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-fn count_zero_wheel(input: &[Rotation]) -> u64 {
-    let mut wheel_zeros = 0;
-    let mut wheel = 50i64;
-    for instruction in input {
-        wheel += (instruction.distance as i64) * instruction.dir.to_factor();
+/// A dial with the positions `0..size` that points at `start` in the beginning and counts how
+/// often it points at one of its targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    start: i64,
+    /// Sorted and without duplicates, all on the dial.
+    targets: Vec<i64>,
+}
 
-        wheel = ((wheel % 100) + 100) % 100;
+/// Position of the dial for all starts at once, see [`Dial::counts_by_start`].
+enum StartPosition {
+    /// The dial points at the start plus this offset.
+    Relative(i64),
+    /// An absolute move set the dial to this position, whatever its start was.
+    Absolute(i64),
+}
 
-        if wheel == 0 {
-            wheel_zeros += 1;
+impl Dial {
+    /// Positions outside of the dial are wrapped onto it, e.g. `-1` is `size - 1`.
+    pub fn new(size: i64, start: i64, targets: impl IntoIterator<Item = i64>) -> Self {
+        assert!(size > 0, "the dial needs at least one position");
+        let mut targets = targets
+            .into_iter()
+            .map(|target| target.rem_euclid(size))
+            .collect::<Vec<_>>();
+        targets.sort_unstable();
+        targets.dedup();
+        Self {
+            size,
+            start: start.rem_euclid(size),
+            targets,
         }
     }
 
-    wheel_zeros
-}
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
 
-fn count_zero_wheel_with_intermediate(input: &[Rotation]) -> u64 {
-    let mut wheel_zeros = 0;
-    let mut wheel = 50i64;
-    for instruction in input {
-        assert!(wheel >= 0);
-        let new_wheel = wheel + (instruction.distance as i64) * instruction.dir.to_factor();
+    pub fn targets(&self) -> &[i64] {
+        &self.targets
+    }
 
-        let new_zeros = ((new_wheel - 50).abs() + 50) / 100;
-        wheel_zeros += new_zeros as u64;
+    fn is_target(&self, position: i64) -> bool {
+        self.targets.binary_search(&position).is_ok()
+    }
 
-        let new_wheel = ((new_wheel % 100) + 100) % 100;
+    /// The position of the dial an absolute move to `position` sets it to.
+    fn wrap(&self, position: u64) -> i64 {
+        (position % self.size as u64) as i64
+    }
 
-        if instruction.dir == Direction::Left && wheel == 0 {
-            // avoid double counting zeros at the end
-            wheel_zeros -= 1;
+    /// Applies `rotation` to the dial at `wheel` and returns the new position and how often the
+    /// dial pointed at a target on the way. The old position is excluded, the new one included.
+    /// An absolute move only points at the position it sets, unless the dial already pointed there.
+    fn rotate(&self, wheel: i64, rotation: Rotation) -> (i64, u64) {
+        let (dir, distance) = match rotation {
+            Rotation::Turn { dir, distance } => (dir, distance),
+            Rotation::Absolute { position } => {
+                let new_wheel = self.wrap(position);
+                let passes = new_wheel != wheel && self.is_target(new_wheel);
+                return (new_wheel, passes as u64);
+            }
+        };
+
+        // every full turn passes every target once, so only the rest of the distance is turned,
+        // in i128 as the dial can be almost as large as i64
+        let turns = distance / self.size as u64;
+        let remainder = i128::from(distance % self.size as u64);
+        let size = i128::from(self.size);
+        let wheel = i128::from(wheel);
+        let (new_wheel, passes) = match dir {
+            Direction::Right => {
+                let new_wheel = wheel + remainder;
+                let passes = self
+                    .targets
+                    .iter()
+                    .map(|&target| {
+                        let target = i128::from(target);
+                        (new_wheel - target).div_euclid(size) - (wheel - target).div_euclid(size)
+                    })
+                    .sum::<i128>();
+                (new_wheel, passes)
+            }
+            // shifted by one, so that a start on a target is not counted again
+            Direction::Left => {
+                let new_wheel = wheel - remainder;
+                let passes = self
+                    .targets
+                    .iter()
+                    .map(|&target| {
+                        let target = i128::from(target);
+                        (wheel - 1 - target).div_euclid(size)
+                            - (new_wheel - 1 - target).div_euclid(size)
+                    })
+                    .sum::<i128>();
+                (new_wheel, passes)
+            }
+        };
+        // a target is passed at most once per step, so this fits into the distance
+        let passes = turns * self.targets.len() as u64 + passes as u64;
+        (new_wheel.rem_euclid(size) as i64, passes)
+    }
+
+    /// Same as [`Dial::rotate`], but with the closed form of the first solution of part 2, which
+    /// mirrors the dial at the position opposite of a target, so that turns to the left count
+    /// like turns to the right. That also counts a start on the target when turning left, which is
+    /// subtracted again. Returns how often that happened as well.
+    fn rotate_mirrored(&self, wheel: i64, rotation: Rotation) -> (i64, u64, u64) {
+        let (dir, distance) = match rotation {
+            Rotation::Turn { dir, distance } => (dir, distance),
+            Rotation::Absolute { .. } => {
+                let (new_wheel, passes) = self.rotate(wheel, rotation);
+                return (new_wheel, passes, 0);
+            }
+        };
+
+        let step = match dir {
+            Direction::Left => -i128::from(distance),
+            Direction::Right => i128::from(distance),
+        };
+        // in half positions, so that the mirror is exact on dials with an odd size
+        let size = 2 * i128::from(self.size);
+        let mut passes = 0;
+        let mut corrected = 0;
+        for &target in &self.targets {
+            // relative to the target
+            let from = i128::from((wheel - target).rem_euclid(self.size));
+            let to = 2 * (from + step);
+            passes += ((to - size / 2).abs() + size / 2) / size;
+            // avoid double counting the target at the start
+            if from == 0 && to <= 0 {
+                passes -= 1;
+                corrected += 1;
+            }
         }
-        wheel = new_wheel;
+        let new_wheel = (i128::from(wheel) + step).rem_euclid(i128::from(self.size));
+        // a target is passed at most once per step, so this fits into the distance
+        (new_wheel as i64, passes as u64, corrected)
     }
 
-    wheel_zeros
-}
+    /// How often the dial points at a target after a whole rotation.
+    pub fn count_landings(&self, input: &[Rotation]) -> u64 {
+        let mut landings = 0;
+        let mut wheel = self.start;
+        for &instruction in input {
+            wheel = self.rotate(wheel, instruction).0;
+
+            if self.is_target(wheel) {
+                landings += 1;
+            }
+        }
+
+        landings
+    }
 
-fn simulate_wheel(input: &[Rotation]) -> u64 {
-    let mut wheel_zeros = 0;
-    let mut wheel = 50i64;
-    for instruction in input {
-        for _ in 0..instruction.distance {
-            assert!(wheel >= 0);
-            wheel += instruction.dir.to_factor();
+    /// How often the dial points at a target during or after a rotation, in closed form per
+    /// rotation. Fails if the count does not fit into 64 bits, which only very long rotations reach.
+    pub fn count_passes(&self, input: &[Rotation]) -> Result<u64, SolveError> {
+        let mut passes = 0;
+        let mut wheel = self.start;
+        for &instruction in input {
+            let (new_wheel, new_passes) = self.rotate(wheel, instruction);
+            passes = sum_passes(passes, new_passes)?;
+            wheel = new_wheel;
+        }
+
+        Ok(passes)
+    }
+
+    /// Same as [`Dial::count_passes`], but with the closed form of the first solution, see
+    /// [`Dial::rotate_mirrored`].
+    pub fn count_passes_mirrored(&self, input: &[Rotation]) -> Result<u64, SolveError> {
+        let mut passes = 0;
+        let mut wheel = self.start;
+        for &instruction in input {
+            let (new_wheel, new_passes, _) = self.rotate_mirrored(wheel, instruction);
+            passes = sum_passes(passes, new_passes)?;
+            wheel = new_wheel;
+        }
+
+        Ok(passes)
+    }
 
-            wheel = ((wheel % 100) + 100) % 100;
-            if wheel == 0 {
-                wheel_zeros += 1;
+    /// Same as [`Dial::count_passes`], but turns the dial step by step.
+    pub fn simulate(&self, input: &[Rotation]) -> u64 {
+        let mut passes = 0;
+        let mut wheel = self.start;
+        for &instruction in input {
+            let (step, distance) = match instruction {
+                Rotation::Turn {
+                    dir: Direction::Left,
+                    distance,
+                } => (-1, distance),
+                Rotation::Turn {
+                    dir: Direction::Right,
+                    distance,
+                } => (1, distance),
+                Rotation::Absolute { position } => {
+                    let new_wheel = self.wrap(position);
+                    if new_wheel != wheel && self.is_target(new_wheel) {
+                        passes += 1;
+                    }
+                    wheel = new_wheel;
+                    continue;
+                }
+            };
+            for _ in 0..distance {
+                wheel = (wheel + step).rem_euclid(self.size);
+                if self.is_target(wheel) {
+                    passes += 1;
+                }
             }
         }
+        passes
     }
-    wheel_zeros
-}
 
-pub fn solve_day01(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
+    /// The positions and counts of the dial after every rotation. The passes are counted like in
+    /// [`Dial::count_passes_mirrored`], including its correction for starts on a target. The totals
+    /// of the last step are the answers of [`Dial::count_landings`] and [`Dial::count_passes`],
+    /// which fails like the latter.
+    pub fn trace(&self, input: &[Rotation]) -> Result<Vec<TraceStep>, SolveError> {
+        let mut wheel = self.start;
+        let mut total_landings = 0;
+        let mut total_passes = 0;
+        input
+            .iter()
+            .map(|&rotation| {
+                let before = wheel;
+                let (passes, corrected);
+                (wheel, passes, corrected) = self.rotate_mirrored(wheel, rotation);
+                let landed = self.is_target(wheel);
+
+                total_landings += landed as u64;
+                total_passes = sum_passes(total_passes, passes)?;
+                Ok(TraceStep {
+                    rotation,
+                    before,
+                    after: wheel,
+                    passes,
+                    corrected,
+                    landed,
+                    total_landings,
+                    total_passes,
+                })
+            })
+            .collect()
+    }
+
+    /// The answers of both parts for every start position of the dial, indexed by the start.
+    ///
+    /// Instead of turning the dial once per start, every rotation adds its count to a range of
+    /// starts. A rotation by `distance` passes every target `distance / size` times from any
+    /// start, and once more from the `distance % size` starts that are at most that far away from
+    /// a target in the direction of the rotation. After an absolute move the dial is at the same
+    /// position for every start, so all further counts are the same for every start.
+    ///
+    /// Fails if the dial has more than [`MAX_START_COUNTS`] positions or a count does not fit into
+    /// 64 bits.
+    pub fn counts_by_start(&self, input: &[Rotation]) -> Result<Vec<StartCounts>, SolveError> {
+        if self.size > MAX_START_COUNTS {
+            return Err(SolveError::new(format!(
+                "the counts of at most {MAX_START_COUNTS} start positions can be computed at once"
+            )));
         }
-    };
+        let size = self.size as usize;
+        let mut landings = vec![0; size];
+        // difference array of the passes, so that ranges of starts can be changed at once
+        let mut passes_diff = vec![0i64; size + 1];
+        // counts that are the same for every start
+        let mut common_landings = 0;
+        let mut common_passes = 0;
+        let mut add_passes = |first: i64, len: i64, passes: i64| {
+            let first = first.rem_euclid(self.size) as usize;
+            let last = first + len as usize;
+            if last <= size {
+                passes_diff[first] += passes;
+                passes_diff[last] -= passes;
+            } else {
+                // wraps around the end of the dial
+                passes_diff[first] += passes;
+                passes_diff[size] -= passes;
+                passes_diff[0] += passes;
+                passes_diff[last - size] -= passes;
+            }
+        };
+
+        let mut position = StartPosition::Relative(0);
+        for &rotation in input {
+            let offset = match position {
+                StartPosition::Relative(offset) => offset,
+                StartPosition::Absolute(wheel) => {
+                    let (new_wheel, passes) = self.rotate(wheel, rotation);
+                    common_passes = sum_passes(common_passes, passes)?;
+                    common_landings += self.is_target(new_wheel) as u64;
+                    position = StartPosition::Absolute(new_wheel);
+                    continue;
+                }
+            };
+
+            let (dir, distance) = match rotation {
+                Rotation::Turn { dir, distance } => (dir, distance),
+                Rotation::Absolute { position: set_to } => {
+                    let new_wheel = self.wrap(set_to);
+                    if self.is_target(new_wheel) {
+                        // every start except the one that already points at it
+                        common_passes = sum_passes(common_passes, 1)?;
+                        add_passes(new_wheel - offset, 1, -1);
+                        common_landings += 1;
+                    }
+                    position = StartPosition::Absolute(new_wheel);
+                    continue;
+                }
+            };
+
+            let turns = distance / self.size as u64;
+            let remainder = (distance % self.size as u64) as i64;
+            let offset = match dir {
+                Direction::Right => {
+                    for &target in &self.targets {
+                        add_passes(target - remainder - offset, remainder, 1);
+                    }
+                    offset + remainder
+                }
+                Direction::Left => {
+                    for &target in &self.targets {
+                        add_passes(target + 1 - offset, remainder, 1);
+                    }
+                    offset - remainder
+                }
+            };
+            common_passes = sum_passes(common_passes, turns * self.targets.len() as u64)?;
+
+            let offset = offset.rem_euclid(self.size);
+            for &target in &self.targets {
+                landings[(target - offset).rem_euclid(self.size) as usize] += 1;
+            }
+            position = StartPosition::Relative(offset);
+        }
+
+        let mut passes = 0;
+        landings
+            .into_iter()
+            .zip(passes_diff)
+            .enumerate()
+            .map(|(start, (landings, diff))| {
+                passes += diff;
+                Ok(StartCounts {
+                    start: start as i64,
+                    landings: common_landings + landings,
+                    // the passes of a start can be below 0 without the common passes
+                    passes: common_passes.checked_add_signed(passes).ok_or_else(|| {
+                        SolveError::new("the dial points at its targets too often for 64 bits")
+                    })?,
+                })
+            })
+            .collect()
+    }
+}
 
-    let start_1 = Instant::now();
-    let sol_task1 = count_zero_wheel(&input);
-    let end_1 = Instant::now();
-    println!(
-        "Task1:\t{}\tTook: {}µs",
-        sol_task1,
-        (end_1 - start_1).as_micros()
-    );
-    let start_2_good = Instant::now();
-    let sol_task2 = count_zero_wheel_with_intermediate(&input);
-    let end_2_good = Instant::now();
-    println!(
-        "Task2:\t{}\tTook: {}µs",
-        sol_task2,
-        (end_2_good - start_2_good).as_micros()
-    );
-    let start_2_bad = Instant::now();
-    let sol_task2_bad = simulate_wheel(&input);
-    let end_2_bad = Instant::now();
-    println!(
-        "Sim2:\t{}\tTook: {}µs",
-        sol_task2_bad,
-        (end_2_bad - start_2_bad).as_micros()
-    );
+/// Largest dial of [`Dial::counts_by_start`], which needs memory for the counts of every position.
+pub const MAX_START_COUNTS: i64 = 1_000_000;
+
+/// Adds the passes of a rotation to the passes so far.
+fn sum_passes(passes: u64, new_passes: u64) -> Result<u64, SolveError> {
+    passes
+        .checked_add(new_passes)
+        .ok_or_else(|| SolveError::new("the dial points at its targets too often for 64 bits"))
+}
+
+/// The answers of both parts if the dial starts at `start`, see [`Dial::counts_by_start`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartCounts {
+    pub start: i64,
+    pub landings: u64,
+    pub passes: u64,
+}
+
+/// The start positions whose counts match all the given counts, `None` matches any count.
+pub fn starts_with_counts(
+    counts: &[StartCounts],
+    landings: Option<u64>,
+    passes: Option<u64>,
+) -> Vec<StartCounts> {
+    counts
+        .iter()
+        .filter(|counts| landings.is_none_or(|landings| counts.landings == landings))
+        .filter(|counts| passes.is_none_or(|passes| counts.passes == passes))
+        .copied()
+        .collect()
+}
+
+/// A single rotation of [`Dial::trace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceStep {
+    pub rotation: Rotation,
+    pub before: i64,
+    pub after: i64,
+    /// How often the dial pointed at a target during the rotation, including where it stopped.
+    pub passes: u64,
+    /// How many targets the dial started on were counted by the closed form and subtracted again.
+    pub corrected: u64,
+    /// Whether the dial stopped at a target.
+    pub landed: bool,
+    pub total_landings: u64,
+    pub total_passes: u64,
+}
+
+pub const DIAL_START: Param = Param {
+    name: "dial_start",
+    kind: ParamKind::Number,
+    default: "50",
+    description: "position the dial points at in the beginning",
+};
+
+pub const DIAL_SIZE: Param = Param {
+    name: "dial_size",
+    kind: ParamKind::Number,
+    default: "100",
+    description: "number of positions on the dial",
+};
+
+pub const TARGETS: Param = Param {
+    name: "targets",
+    kind: ParamKind::Numbers,
+    default: "0",
+    description: "positions the dial counts, positions beyond the dial are wrapped onto it",
+};
+
+/// The dial of the puzzle, which counts how often it points at 0 unless other targets are given.
+pub fn dial(params: &Params) -> Result<Dial, SolveError> {
+    let size: i64 = params.get(&DIAL_SIZE)?;
+    if size == 0 {
+        return Err(SolveError::new("the dial needs at least one position"));
+    }
+    let start: i64 = params.get(&DIAL_START)?;
+    let targets: Vec<i64> = params.get_list(&TARGETS)?;
+    Ok(Dial::new(size, start, targets))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<Rotation>;
+
+    const PARAMS: &'static [Param] = &[DIAL_START, DIAL_SIZE, TARGETS];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(dial(params)?.count_landings(input))
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        dial(params)?.count_passes(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, DEFAULT_VARIANT, Self::part1),
+            Variant::new(2, "intermediate", Self::part2),
+            Variant::new(2, "simulate", |input, params| {
+                Ok(dial(params)?.simulate(input))
+            }),
+            Variant::new(2, "mirrored", |input, params| {
+                dial(params)?.count_passes_mirrored(input)
+            }),
+        ]
+    }
 }
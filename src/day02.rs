@@ -1,47 +1,52 @@
-use std::time::Instant;
+use std::ops::RangeInclusive;
 
-type ID = u64;
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError, Variant},
+};
+
+pub type ID = u64;
 #[derive(Debug)]
-struct IDRange {
-    start: ID,
-    end: ID,
+pub struct IDRange {
+    pub start: ID,
+    pub end: ID,
 }
 
 impl IDRange {
-    fn from_str<S: AsRef<str>>(s: S) -> Option<Self> {
+    fn from_str<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
         let s_ = s.as_ref();
-        let mut ids = s_.split("-");
-        let Some(start_str) = ids.next() else {
-            return None;
-        };
-        let Some(end_str) = ids.next() else {
-            return None;
+        let Some((start_str, end_str)) = s_.split_once("-") else {
+            return Err(ParseError::at(s_, s_, "an ID range (start-end)"));
         };
-        if ids.next().is_some() {
-            return None;
-        }
         let start: ID = match start_str.parse() {
             Ok(start) => start,
-            Err(_) => return None,
+            Err(_) => return Err(ParseError::at(s_, start_str, "a start ID")),
         };
         let end: ID = match end_str.parse() {
             Ok(end) => end,
-            Err(_) => return None,
+            Err(_) => return Err(ParseError::at(s_, end_str, "an end ID")),
         };
 
-        Some(Self { start, end })
+        Ok(Self { start, end })
     }
 }
 
-fn find_invalid_ids_task_1(input: &[IDRange]) -> impl Iterator<Item = u64> {
+/// Number of decimal digits of `id`, where 0 has one digit like in its string.
+fn num_digits(id: ID) -> u32 {
+    id.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+pub fn find_invalid_ids_task_1(input: &[IDRange]) -> impl Iterator<Item = u64> {
     input
         .iter()
         .flat_map(|range| range.start..=range.end)
         .filter(|id| {
-            let num_id_digits = id.ilog10() + 1;
+            let num_id_digits = num_digits(*id);
 
             // ignore numbers where we would need to consider leading zeros
-            if num_id_digits % 2 != 0 {
+            if !num_id_digits.is_multiple_of(2) {
                 return false;
             }
 
@@ -54,7 +59,7 @@ fn find_invalid_ids_task_1(input: &[IDRange]) -> impl Iterator<Item = u64> {
         })
 }
 
-fn find_invalid_ids_task_1_slow(input: &[IDRange]) -> impl Iterator<Item = u64> {
+pub fn find_invalid_ids_task_1_slow(input: &[IDRange]) -> impl Iterator<Item = u64> {
     input
         .iter()
         .flat_map(|range| range.start..=range.end)
@@ -71,12 +76,12 @@ fn find_invalid_ids_task_1_slow(input: &[IDRange]) -> impl Iterator<Item = u64>
         })
 }
 
-fn find_invalid_ids_task2(input: &[IDRange]) -> impl Iterator<Item = u64> {
+pub fn find_invalid_ids_task2(input: &[IDRange]) -> impl Iterator<Item = u64> {
     input
         .iter()
         .flat_map(|range| range.start..=range.end)
         .filter(|id| {
-            let num_id_digits = id.ilog10() + 1;
+            let num_id_digits = num_digits(*id);
 
             for num_pieces in 2..=num_id_digits {
                 if !num_id_digits.is_multiple_of(num_pieces) {
@@ -94,7 +99,8 @@ fn find_invalid_ids_task2(input: &[IDRange]) -> impl Iterator<Item = u64> {
                         is_valid = true;
                         break;
                     }
-                    running_digit_int *= digit_int;
+                    // saturates after the last piece of IDs with 20 digits
+                    running_digit_int = running_digit_int.saturating_mul(digit_int);
                 }
 
                 if !is_valid {
@@ -106,7 +112,7 @@ fn find_invalid_ids_task2(input: &[IDRange]) -> impl Iterator<Item = u64> {
         })
 }
 
-fn find_invalid_ids_task2_slow(input: &[IDRange]) -> impl Iterator<Item = u64> {
+pub fn find_invalid_ids_task2_slow(input: &[IDRange]) -> impl Iterator<Item = u64> {
     input
         .iter()
         .flat_map(|range| range.start..=range.end)
@@ -140,52 +146,178 @@ fn find_invalid_ids_task2_slow(input: &[IDRange]) -> impl Iterator<Item = u64> {
         })
 }
 
-fn parse_input(input_data: String) -> Option<Vec<IDRange>> {
+/// Maximum number of digits of an [`ID`].
+const MAX_ID_DIGITS: u32 = ID::MAX.ilog10() + 1;
+
+/// The blocks per number of block digits, whose repetition `repeats` times lies in `range`,
+/// together with the multiplier that repeats them.
+///
+/// The multiplier has a one at the start of every block, e.g. 1001001 for a block of three digits
+/// repeated three times. `repeats` has to be at least 2.
+fn repeated_blocks(
+    range: &IDRange,
+    repeats: u32,
+) -> impl Iterator<Item = (RangeInclusive<ID>, ID)> {
+    assert!(repeats >= 2, "a block has to be repeated at least twice");
+    let (start, end) = (range.start, range.end);
+    (1..=MAX_ID_DIGITS / repeats).filter_map(move |block_digits| {
+        let block_int = 10u64.pow(block_digits);
+        let multiplier = (0..repeats).try_fold(0u64, |multiplier, _| {
+            multiplier.checked_mul(block_int)?.checked_add(1)
+        })?;
+        // blocks without leading zeros
+        let blocks = (block_int / 10).max(start.div_ceil(multiplier))
+            ..=(block_int - 1).min(end / multiplier);
+        Some((blocks, multiplier))
+    })
+}
+
+/// All IDs of `range` whose digits are a block of digits repeated `repeats` times, ascending.
+///
+/// Instead of testing every ID, they are constructed as the block times the multiplier of
+/// [`repeated_blocks`], so only the matching IDs are visited.
+pub fn repeated_ids(range: &IDRange, repeats: u32) -> impl Iterator<Item = ID> {
+    repeated_blocks(range, repeats)
+        .flat_map(|(blocks, multiplier)| blocks.map(move |block| block * multiplier))
+}
+
+/// Sum of [`repeated_ids`] without visiting them, as the blocks of every number of block digits
+/// are consecutive and their sum is the sum of an arithmetic series.
+pub fn sum_repeated_ids(range: &IDRange, repeats: u32) -> u128 {
+    repeated_blocks(range, repeats)
+        .filter(|(blocks, _)| !blocks.is_empty())
+        .map(|(blocks, multiplier)| {
+            let (first, last) = (u128::from(*blocks.start()), u128::from(*blocks.end()));
+            (first + last) * (last - first + 1) / 2 * u128::from(multiplier)
+        })
+        .sum()
+}
+
+pub fn find_invalid_ids_task_1_arithmetic(input: &[IDRange]) -> impl Iterator<Item = u64> {
+    input.iter().flat_map(|range| repeated_ids(range, 2))
+}
+
+/// Same sum as [`find_invalid_ids_task_1_arithmetic`], but with [`sum_repeated_ids`], `None` if
+/// the sum does not fit into an [`ID`].
+pub fn sum_invalid_ids_task_1_arithmetic(input: &[IDRange]) -> Option<u64> {
+    let sum = input
+        .iter()
+        .map(|range| sum_repeated_ids(range, 2))
+        .sum::<u128>();
+    u64::try_from(sum).ok()
+}
+
+/// Same sum as [`find_invalid_ids_task2`], but the invalid IDs are summed with
+/// [`sum_repeated_ids`].
+///
+/// An ID is invalid if it repeats a block a prime number of times, e.g. 111111 repeats 111 twice
+/// and 11 three times. To count such IDs once, the IDs are summed by inclusion–exclusion over the
+/// products of distinct primes: 111111 is added for 2 and 3 and subtracted again for 6. `None` if
+/// the sum does not fit into an [`ID`].
+pub fn sum_invalid_ids_task2_arithmetic(input: &[IDRange]) -> Option<u64> {
+    let mut sum = 0i128;
+    for repeats in 2..=MAX_ID_DIGITS {
+        let Some(sign) = inclusion_exclusion_sign(repeats) else {
+            continue;
+        };
+        for range in input {
+            // the repeated IDs of a range sum up to less than 2^98, far below the maximum of i128
+            sum += sign * sum_repeated_ids(range, repeats) as i128;
+        }
+    }
+    u64::try_from(sum).ok()
+}
+
+/// Sum of the IDs, `None` if it does not fit into an [`ID`].
+pub fn checked_sum(ids: impl IntoIterator<Item = ID>) -> Option<ID> {
+    ids.into_iter().try_fold(0, ID::checked_add)
+}
+
+fn sum_answer(sum: Option<ID>) -> Result<Answer, SolveError> {
+    sum.ok_or_else(|| SolveError::new("the sum of the invalid IDs does not fit into 64 bits"))
+}
+
+/// 1 for a product of an odd number of distinct primes, -1 for an even number and `None` if a prime
+/// divides `n` more than once.
+fn inclusion_exclusion_sign(mut n: u32) -> Option<i128> {
+    let mut sign = -1;
+    let mut prime = 2;
+    while n > 1 {
+        if n.is_multiple_of(prime) {
+            n /= prime;
+            if n.is_multiple_of(prime) {
+                return None;
+            }
+            sign = -sign;
+        }
+        prime += 1;
+    }
+    Some(sign)
+}
+
+pub fn parse_input(input_data: &str) -> Result<Vec<IDRange>, ParseError> {
     input_data
         .trim()
         .split(",")
-        .map(IDRange::from_str)
+        .map(|range_str| {
+            IDRange::from_str(range_str).map_err(|err| err.within(input_data, range_str))
+        })
         .collect()
 }
 
-pub fn solve_day02(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            println!("Could not parse input!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1: u64 = find_invalid_ids_task_1(&input).sum();
-    let end_1 = Instant::now();
-    println!(
-        "Task 1: {sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_1_slow = Instant::now();
-    let sol_task1_slow: u64 = find_invalid_ids_task_1_slow(&input).sum();
-    let end_1_slow = Instant::now();
-    println!(
-        "Slow 1: {sol_task1_slow}\tTook: {}µs",
-        (end_1_slow - start_1_slow).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2: u64 = find_invalid_ids_task2(&input).sum();
-    let end_2: Instant = Instant::now();
-    println!(
-        "Task 2: {sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
-
-    let start_2_slow = Instant::now();
-    let sol_task2_slow: u64 = find_invalid_ids_task2_slow(&input).sum();
-    let end_2_slow: Instant = Instant::now();
-    println!(
-        "Slow 2: {sol_task2_slow}\tTook: {}µs",
-        (end_2_slow - start_2_slow).as_micros()
-    );
+/// `size` random ranges of IDs with up to 10 digits and at most 100000 IDs each.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let num_digits = rng.range(1..=10) as u32;
+            let start = rng.range(10u64.pow(num_digits - 1)..=10u64.pow(num_digits) - 1);
+            let end = start + rng.range(0..=100_000);
+            format!("{start}-{end}")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<IDRange>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        sum_answer(sum_invalid_ids_task_1_arithmetic(input))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        sum_answer(sum_invalid_ids_task2_arithmetic(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        // the variants that test every ID only finish for small ranges, so they only run if they
+        // are selected and are compared against the arithmetic ones
+        vec![
+            Variant::new(1, "arithmetic", Self::part1),
+            Variant::opt_in(1, "digits", |input, _| {
+                sum_answer(checked_sum(find_invalid_ids_task_1(input)))
+            }),
+            Variant::opt_in(1, "string", |input, _| {
+                sum_answer(checked_sum(find_invalid_ids_task_1_slow(input)))
+            }),
+            Variant::new(2, "arithmetic", Self::part2),
+            Variant::opt_in(2, "digits", |input, _| {
+                sum_answer(checked_sum(find_invalid_ids_task2(input)))
+            }),
+            Variant::opt_in(2, "string", |input, _| {
+                sum_answer(checked_sum(find_invalid_ids_task2_slow(input)))
+            }),
+        ]
+    }
 }
@@ -1,70 +1,130 @@
-use std::time::Instant;
+use crate::{
+    generate::Rng,
+    params::{Param, ParamKind, Params},
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
 
 #[derive(Debug)]
-struct Bank {
-    batteries: Vec<u32>,
+pub struct Bank {
+    pub batteries: Vec<u32>,
 }
 
 impl Bank {
-    fn from_str<S: AsRef<str>>(s: S) -> Option<Self> {
+    fn from_str<S: AsRef<str>>(s: S) -> Result<Self, ParseError> {
         let s_ = s.as_ref();
 
         let batteries = s_
-            .chars()
-            .map(|c| c.to_digit(10))
-            .collect::<Option<Vec<u32>>>()?;
+            .char_indices()
+            .map(|(idx, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    ParseError::at(s_, &s_[idx..idx + c.len_utf8()], "a battery (0-9)")
+                })
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
 
-        Some(Self { batteries })
+        Ok(Self { batteries })
     }
 }
 
-fn parse_input(input_data: String) -> Option<Vec<Bank>> {
-    input_data.lines().map(Bank::from_str).collect()
+pub fn parse_input(input_data: &str) -> Result<Vec<Bank>, ParseError> {
+    input_data
+        .lines()
+        .map(|line| Bank::from_str(line).map_err(|err| err.within(input_data, line)))
+        .collect()
 }
 
-fn max_jolts(bank: &[Bank], num_batteries: usize) -> impl Iterator<Item = u64> {
+/// `size` random banks of 100 batteries each.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..100)
+                .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The maximal joltage of every bank, `None` if the bank has less than `num_batteries` batteries.
+pub fn max_jolts(bank: &[Bank], num_batteries: usize) -> impl Iterator<Item = Option<u64>> {
     bank.iter().map(move |bank| {
         let mut start_idx = 0;
         let mut acc: u64 = 0;
 
         for battery_idx in (0..num_batteries).rev() {
-            let (max_idx, max_val) = bank.batteries
-                [start_idx..(bank.batteries.len() - battery_idx)]
+            let end_idx = bank.batteries.len().checked_sub(battery_idx)?;
+            let (max_idx, max_val) = bank
+                .batteries
+                .get(start_idx..end_idx)?
                 .iter()
                 .enumerate()
-                .max_by(|&(idx_a, val_a), &(idx_b, val_b)| val_a.cmp(val_b).then(idx_b.cmp(&idx_a)))
-                .expect("The bank has not enough batteries!");
+                .max_by(|&(idx_a, val_a), &(idx_b, val_b)| {
+                    val_a.cmp(val_b).then(idx_b.cmp(&idx_a))
+                })?;
 
             start_idx += max_idx + 1;
             acc = acc * 10 + *max_val as u64;
         }
 
-        acc
+        Some(acc)
     })
 }
 
-pub fn solve_day03(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            println!("Could not parse input!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1: u64 = max_jolts(&input, 2).sum();
-    let end_1 = Instant::now();
-    println!(
-        "Task 1: {sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2: u64 = max_jolts(&input, 12).sum();
-    let end_2 = Instant::now();
-    println!(
-        "Task 2: {sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+fn sum_max_jolts(bank: &[Bank], num_batteries: usize) -> Result<Answer, SolveError> {
+    // the joltage of 20 batteries may already overflow
+    if num_batteries > 19 {
+        return Err(SolveError::new("at most 19 batteries can be turned on"));
+    }
+    let mut sum: u64 = 0;
+    for jolts in max_jolts(bank, num_batteries) {
+        let Some(jolts) = jolts else {
+            return Err(SolveError::new(format!(
+                "every bank needs at least {num_batteries} batteries"
+            )));
+        };
+        sum = sum
+            .checked_add(jolts)
+            .ok_or_else(|| SolveError::new("the sum of the joltages does not fit into 64 bits"))?;
+    }
+    Ok(sum)
+}
+
+pub const BATTERIES_TASK_1: Param = Param {
+    name: "batteries1",
+    kind: ParamKind::Number,
+    default: "2",
+    description: "number of batteries that are turned on per bank in task 1",
+};
+
+pub const BATTERIES_TASK_2: Param = Param {
+    name: "batteries2",
+    kind: ParamKind::Number,
+    default: "12",
+    description: "number of batteries that are turned on per bank in task 2",
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Bank>;
+
+    const PARAMS: &'static [Param] = &[BATTERIES_TASK_1, BATTERIES_TASK_2];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        sum_max_jolts(input, params.get(&BATTERIES_TASK_1)?)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        sum_max_jolts(input, params.get(&BATTERIES_TASK_2)?)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
@@ -1,17 +1,32 @@
-use std::{ops::Index, time::Instant};
+use std::ops::Index;
 
 use bitvec::prelude::*;
 
 use smallvec::SmallVec;
 
+use crate::{
+    generate::Rng,
+    params::{Param, ParamKind, Params},
+    parse::ParseError,
+    solution::{Answer, DEFAULT_VARIANT, Solution, SolveError, Variant},
+};
+
 #[derive(Debug, Clone)]
-struct Grid {
+pub struct Grid {
     data: BitVec,
     width: usize,
     height: usize,
 }
 
 impl Grid {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     fn calc_data_idx(&self, x: usize, y: usize) -> usize {
         self.width * y + x
     }
@@ -23,28 +38,38 @@ impl Grid {
 }
 
 impl TryFrom<&str> for Grid {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let s: &str = value.as_ref();
-        let Some(width) = s.lines().next().map(str::len) else {
-            return Err(());
-        };
-        let Some(data): Option<BitVec> = s
-            .lines()
-            .flat_map(|line| {
-                assert!(line.len() == width);
-                line.chars().map(|c| match c {
-                    '.' => Some(false),
-                    '@' => Some(true),
-                    _ => None,
-                })
-            })
-            .collect()
-        else {
-            return Err(());
+        let Some(width) = s.lines().next().map(str::len).filter(|&width| width > 0) else {
+            return Err(ParseError::at(s, s, "a grid of '.' and '@'"));
         };
 
+        let mut data = BitVec::with_capacity(s.len());
+        for line in s.lines() {
+            if line.len() != width {
+                return Err(ParseError::at(
+                    s,
+                    line,
+                    format!("a line with {width} cells"),
+                ));
+            }
+            for (idx, c) in line.char_indices() {
+                match c {
+                    '.' => data.push(false),
+                    '@' => data.push(true),
+                    _ => {
+                        return Err(ParseError::at(
+                            s,
+                            &line[idx..idx + c.len_utf8()],
+                            "'.' or '@'",
+                        ));
+                    }
+                }
+            }
+        }
+
         let height = data.len() / width;
         Ok(Self {
             data,
@@ -71,8 +96,20 @@ impl Index<(usize, usize)> for Grid {
     }
 }
 
-fn parse_input(input_data: String) -> Option<Grid> {
-    Grid::try_from(input_data.as_ref()).ok()
+pub fn parse_input(input_data: &str) -> Result<Grid, ParseError> {
+    Grid::try_from(input_data)
+}
+
+/// A random `size` x `size` grid, in which most cells are rolls.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn iter_neighbours(
@@ -108,18 +145,22 @@ fn find_neighbouring_rolls(
         .filter(|&(n_x, n_y)| grid[(n_x, n_y)])
 }
 
-fn can_roll_be_removed(grid: &Grid, x: isize, y: isize) -> bool {
-    find_neighbouring_rolls(grid, x, y).count() < 4
+fn can_roll_be_removed(grid: &Grid, x: isize, y: isize, neighbour_limit: usize) -> bool {
+    find_neighbouring_rolls(grid, x, y).count() < neighbour_limit
 }
 
-fn find_moveable_rolls(grid: &Grid) -> impl Iterator<Item = (usize, usize)> {
+/// All rolls with less than `neighbour_limit` neighbouring rolls.
+pub fn find_moveable_rolls(
+    grid: &Grid,
+    neighbour_limit: usize,
+) -> impl Iterator<Item = (usize, usize)> {
     (0..grid.width).flat_map(move |x| {
         (0..grid.height).filter_map(move |y| {
             if !grid[(x, y)] {
                 return None;
             }
 
-            if can_roll_be_removed(grid, x as isize, y as isize) {
+            if can_roll_be_removed(grid, x as isize, y as isize, neighbour_limit) {
                 Some((x, y))
             } else {
                 None
@@ -128,10 +169,10 @@ fn find_moveable_rolls(grid: &Grid) -> impl Iterator<Item = (usize, usize)> {
     })
 }
 
-fn solve_task2_naive(mut grid: Grid) -> usize {
+pub fn solve_task2_naive(mut grid: Grid, neighbour_limit: usize) -> usize {
     let mut removed_rolls = 0;
     loop {
-        let moveable_rolls = find_moveable_rolls(&grid).collect::<Vec<_>>();
+        let moveable_rolls = find_moveable_rolls(&grid, neighbour_limit).collect::<Vec<_>>();
 
         if moveable_rolls.is_empty() {
             break;
@@ -147,7 +188,7 @@ fn solve_task2_naive(mut grid: Grid) -> usize {
     removed_rolls
 }
 
-fn solve_task2_smarter(mut grid: Grid) -> usize {
+pub fn solve_task2_smarter(mut grid: Grid, neighbour_limit: usize) -> usize {
     let grid_height = grid.height;
     let mut whole_iter =
         (0..grid.width as isize).flat_map(|x| (0..grid_height as isize).map(move |y| (x, y)));
@@ -171,7 +212,7 @@ fn solve_task2_smarter(mut grid: Grid) -> usize {
         let neighbouring_rolls =
             find_neighbouring_rolls(&grid, x, y).collect::<SmallVec<[(isize, isize); 8]>>();
 
-        if neighbouring_rolls.len() < 4 {
+        if neighbouring_rolls.len() < neighbour_limit {
             grid.set(x as usize, y as usize, false);
             removed_rolls += 1;
             stack.extend(neighbouring_rolls);
@@ -180,7 +221,7 @@ fn solve_task2_smarter(mut grid: Grid) -> usize {
     removed_rolls
 }
 
-fn solve_task2_smartest(mut grid: Grid) -> usize {
+pub fn solve_task2_smartest(mut grid: Grid, neighbour_limit: usize) -> usize {
     let grid_ = &grid;
     let mut num_neighbours = (0..grid.height as isize)
         .flat_map(|y| {
@@ -192,7 +233,7 @@ fn solve_task2_smartest(mut grid: Grid) -> usize {
         .iter()
         .enumerate()
         .filter_map(|(pos, num_neighbours)| {
-            if *num_neighbours < 4 {
+            if *num_neighbours < neighbour_limit {
                 let y = pos / grid.width;
                 let x = pos - grid.width * y;
                 if grid[(x, y)] { Some((x, y)) } else { None }
@@ -208,7 +249,7 @@ fn solve_task2_smartest(mut grid: Grid) -> usize {
             continue;
         }
         debug_assert!(grid[(x, y)]);
-        debug_assert!(num_neighbours[grid.width * y + x] < 4);
+        debug_assert!(num_neighbours[grid.width * y + x] < neighbour_limit);
 
         grid.set(x, y, false);
         removed_rolls += 1;
@@ -229,7 +270,7 @@ fn solve_task2_smartest(mut grid: Grid) -> usize {
             debug_assert!(num_neighbours[idx] > 0, "{x}, {y}, {n_x}, {n_y}, {idx}");
             num_neighbours[idx] -= 1;
 
-            if num_neighbours[idx] < 4 {
+            if num_neighbours[idx] < neighbour_limit {
                 stack.push((n_x as usize, n_y as usize));
             }
         }
@@ -238,47 +279,47 @@ fn solve_task2_smartest(mut grid: Grid) -> usize {
     removed_rolls
 }
 
-pub fn solve_day04(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            println!("Could not parse input!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1: usize = find_moveable_rolls(&input).count();
-    let end_1 = Instant::now();
-    println!(
-        "Task 1:\t\t{sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let input_ = input.clone();
-    let start_2_naive = Instant::now();
-    let sol_task2_naive: usize = solve_task2_naive(input_);
-    let end_2_naive = Instant::now();
-    println!(
-        "Naive 2:\t{sol_task2_naive}\tTook: {}µs",
-        (end_2_naive - start_2_naive).as_micros()
-    );
-
-    let input_ = input.clone();
-    let start_2_smarter = Instant::now();
-    let sol_task2_smarter: usize = solve_task2_smarter(input_);
-    let end_2_smarter = Instant::now();
-    println!(
-        "Smarter 2:\t{sol_task2_smarter}\tTook: {}µs",
-        (end_2_smarter - start_2_smarter).as_micros()
-    );
-
-    let input_ = input.clone();
-    let start_2_smartest = Instant::now();
-    let sol_task2_smartest: usize = solve_task2_smartest(input_);
-    let end_2_smartest = Instant::now();
-    println!(
-        "Smartest 2:\t{sol_task2_smartest}\tTook: {}µs",
-        (end_2_smartest - start_2_smartest).as_micros()
-    );
+pub const NEIGHBOUR_LIMIT: Param = Param {
+    name: "neighbours",
+    kind: ParamKind::Number,
+    default: "4",
+    description: "a roll can be removed if less than this number of rolls neighbour it",
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid;
+
+    const PARAMS: &'static [Param] = &[NEIGHBOUR_LIMIT];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(find_moveable_rolls(input, params.get(&NEIGHBOUR_LIMIT)?).count() as Answer)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(solve_task2_smartest(input.clone(), params.get(&NEIGHBOUR_LIMIT)?) as Answer)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, DEFAULT_VARIANT, Self::part1),
+            Variant::new(2, "smartest", Self::part2),
+            Variant::new(2, "smarter", |input, params| {
+                Ok(solve_task2_smarter(input.clone(), params.get(&NEIGHBOUR_LIMIT)?) as Answer)
+            }),
+            Variant::new(2, "naive", |input, params| {
+                Ok(solve_task2_naive(input.clone(), params.get(&NEIGHBOUR_LIMIT)?) as Answer)
+            }),
+        ]
+    }
 }
@@ -1,83 +1,101 @@
-use std::{cmp::Ordering, time::Instant};
+use std::cmp::Ordering;
 
-type ID = u64;
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, DEFAULT_VARIANT, Solution, SolveError, Variant},
+};
+
+pub type ID = u64;
 
 #[derive(Debug, Clone, Copy)]
-struct IDRange {
-    start: ID,
-    end: ID,
+pub struct IDRange {
+    pub start: ID,
+    pub end: ID,
 }
 
 #[derive(Debug, Clone)]
-struct DB {
-    fresh_r: Vec<IDRange>,
-    ids: Vec<ID>,
+pub struct DB {
+    pub fresh_r: Vec<IDRange>,
+    pub ids: Vec<ID>,
 }
 
-fn parse_input(input_data: String) -> Option<DB> {
+pub fn parse_input(input_data: &str) -> Result<DB, ParseError> {
     let mut splits = input_data.split("\n\n");
     let Some(ranges_str) = splits.next() else {
-        return None;
+        return Err(ParseError::at(input_data, input_data, "fresh ID ranges"));
     };
     let Some(ids_str) = splits.next() else {
-        return None;
-    };
-    let None = splits.next() else {
-        return None;
+        return Err(ParseError::at(
+            input_data,
+            &input_data[input_data.len()..],
+            "an empty line followed by the available IDs",
+        ));
     };
+    if let Some(rest) = splits.next() {
+        return Err(ParseError::at(input_data, rest, "the end of the input"));
+    }
 
-    let ranges_opt: Option<Vec<IDRange>> = ranges_str
+    let ranges = ranges_str
         .lines()
         .map(|line| {
-            let mut range_splits = line.split("-");
-            let Some(start_str) = range_splits.next() else {
-                return None;
-            };
-            let Some(end_str) = range_splits.next() else {
-                return None;
-            };
-            let None = range_splits.next() else {
-                return None;
+            let Some((start_str, end_str)) = line.split_once("-") else {
+                return Err(ParseError::at(input_data, line, "an ID range (start-end)"));
             };
 
             let Ok(start): Result<ID, _> = start_str.parse() else {
-                return None;
+                return Err(ParseError::at(input_data, start_str, "a start ID"));
             };
             let Ok(end): Result<ID, _> = end_str.parse() else {
-                return None;
+                return Err(ParseError::at(input_data, end_str, "an end ID"));
             };
 
             if start > end {
-                return None;
+                return Err(ParseError::at(
+                    input_data,
+                    end_str,
+                    format!("an end ID not below {start}"),
+                ));
             }
 
-            Some(IDRange { start, end })
+            Ok(IDRange { start, end })
         })
-        .collect();
-    let Some(ranges) = ranges_opt else {
-        return None;
-    };
+        .collect::<Result<Vec<IDRange>, _>>()?;
 
-    let ids_opt: Option<Vec<ID>> = ids_str
+    let ids = ids_str
         .lines()
         .map(|line| {
             let Ok(id) = line.parse() else {
-                return None;
+                return Err(ParseError::at(input_data, line, "an ID"));
             };
-            Some(id)
+            Ok(id)
         })
-        .collect();
-    let Some(ids) = ids_opt else {
-        return None;
-    };
+        .collect::<Result<Vec<ID>, _>>()?;
 
-    Some(DB {
+    Ok(DB {
         fresh_r: ranges,
         ids,
     })
 }
 
-fn find_fresh_ids_naive(db: &DB) -> impl Iterator<Item = ID> {
+/// A random database of `size` fresh ID ranges and `size` available IDs. The ranges are kept short,
+/// as task 2 counts the fresh IDs one by one.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let ranges = (0..size).map(|_| {
+        let start = rng.range(1..=1_000_000);
+        let end = start + rng.range(0..=10_000);
+        format!("{start}-{end}")
+    });
+    let ranges = ranges.collect::<Vec<_>>().join("\n");
+    let ids = (0..size)
+        .map(|_| rng.range(1..=1_010_000).to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{ranges}\n\n{ids}")
+}
+
+pub fn find_fresh_ids_naive(db: &DB) -> impl Iterator<Item = ID> {
     db.ids
         .iter()
         .filter(|&&id| {
@@ -112,10 +130,9 @@ fn prepare_sorted_range_array(db: &mut DB) {
     // also push the last one too
     merged.push(current);
     db.fresh_r = merged;
-    println!("{}", db.fresh_r.len());
 }
 
-fn find_fresh_ids_smart(db: &mut DB) -> impl Iterator<Item = ID> {
+pub fn find_fresh_ids_smart(db: &mut DB) -> impl Iterator<Item = ID> {
     prepare_sorted_range_array(db);
 
     db.ids
@@ -136,43 +153,42 @@ fn find_fresh_ids_smart(db: &mut DB) -> impl Iterator<Item = ID> {
         .copied()
 }
 
-fn find_fresh_id_ranges(db: &mut DB) -> impl Iterator<Item = ID> {
+pub fn find_fresh_id_ranges(db: &mut DB) -> impl Iterator<Item = ID> {
     prepare_sorted_range_array(db);
     db.fresh_r.iter().flat_map(|range| range.start..=range.end)
 }
 
-pub fn solve_day05(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
+pub struct Day05;
 
-    let start_1_naive = Instant::now();
-    let sol_task1_naive: usize = find_fresh_ids_naive(&input).count();
-    let end_1_naive = Instant::now();
-    println!(
-        "Naive 1:\t\t{sol_task1_naive}\tTook: {}µs",
-        (end_1_naive - start_1_naive).as_micros()
-    );
-
-    let mut input_ = input.clone();
-    let start_1_smart = Instant::now();
-    let sol_task1_smart: usize = find_fresh_ids_smart(&mut input_).count();
-    let end_1_smart = Instant::now();
-    println!(
-        "Smart 1:\t\t{sol_task1_smart}\tTook: {}µs",
-        (end_1_smart - start_1_smart).as_micros()
-    );
-
-    let mut input_ = input.clone();
-    let start_2 = Instant::now();
-    let sol_task2: usize = find_fresh_id_ranges(&mut input_).count();
-    let end_2 = Instant::now();
-    println!(
-        "Task 2:\t\t{sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = DB;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        let mut input_ = input.clone();
+        Ok(find_fresh_ids_smart(&mut input_).count() as Answer)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        let mut input_ = input.clone();
+        Ok(find_fresh_id_ranges(&mut input_).count() as Answer)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, "smart", Self::part1),
+            Variant::new(1, "naive", |input, _| {
+                Ok(find_fresh_ids_naive(input).count() as Answer)
+            }),
+            Variant::new(2, DEFAULT_VARIANT, Self::part2),
+        ]
+    }
 }
@@ -1,65 +1,80 @@
-use std::time::Instant;
-
 use smallvec::{SmallVec, smallvec};
 
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum MathOp {
+pub enum MathOp {
     Add,
     Mul,
 }
 
 impl TryFrom<&str> for MathOp {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.len() != 1 {
-            return Err(());
-        }
-
-        match value.chars().next().unwrap() {
-            '+' => Ok(Self::Add),
-            '*' => Ok(Self::Mul),
-            _ => Err(()),
+        match value {
+            "+" => Ok(Self::Add),
+            "*" => Ok(Self::Mul),
+            _ => Err(ParseError::at(value, value, "an operator (+ or *)")),
         }
     }
 }
 
 #[derive(Debug)]
-struct Problem {
-    inputs: SmallVec<[u64; 4]>,
-    op: Option<MathOp>,
+pub struct Problem {
+    pub inputs: SmallVec<[u64; 4]>,
+    pub op: Option<MathOp>,
 }
 
 impl Problem {
-    pub fn solve(&self) -> u64 {
-        match self.op.unwrap() {
-            MathOp::Add => self.inputs.iter().sum(),
-            MathOp::Mul => self.inputs.iter().product(),
+    /// The result of the problem, `None` if the problem has no operator.
+    pub fn solve(&self) -> Option<u64> {
+        match self.op? {
+            MathOp::Add => Some(self.inputs.iter().sum()),
+            MathOp::Mul => Some(self.inputs.iter().product()),
         }
     }
 }
 
-fn parse_input1<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
+pub fn parse_input1<S: AsRef<str>>(input_data_: S) -> Result<Vec<Problem>, ParseError> {
     let input_data = input_data_.as_ref();
     let mut problems = Vec::<Problem>::new();
 
     for line in input_data.lines() {
         let mut splits = line.split_ascii_whitespace();
         let Some(first_elem_str) = splits.next() else {
-            return None;
+            return Err(ParseError::at(input_data, line, "a number or an operator"));
         };
         if let Ok(first_op) = first_elem_str.trim().try_into() {
-            problems[0].op = Some(first_op);
+            let Some(first_problem) = problems.first_mut() else {
+                return Err(ParseError::at(input_data, first_elem_str, "a number"));
+            };
+            first_problem.op = Some(first_op);
 
             for (i, math_op_str) in splits.enumerate() {
-                let Ok(op) = math_op_str.try_into() else {
-                    return None;
+                let op = MathOp::try_from(math_op_str)
+                    .map_err(|err| err.within(input_data, math_op_str))?;
+                let Some(problem) = problems.get_mut(i + 1) else {
+                    return Err(ParseError::at(
+                        input_data,
+                        math_op_str,
+                        "an operator below a column of numbers",
+                    ));
                 };
-                problems[i + 1].op = Some(op);
+                problem.op = Some(op);
             }
         } else {
             let Ok(first_num) = first_elem_str.parse() else {
-                return None;
+                return Err(ParseError::at(
+                    input_data,
+                    first_elem_str,
+                    "a number or an operator",
+                ));
             };
             if problems.is_empty() {
                 problems.push(Problem {
@@ -71,7 +86,7 @@ fn parse_input1<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
             }
             for (i, num_str) in splits.enumerate() {
                 let Ok(num) = num_str.parse() else {
-                    return None;
+                    return Err(ParseError::at(input_data, num_str, "a number"));
                 };
                 if problems.len() <= i + 1 {
                     problems.push(Problem {
@@ -85,10 +100,10 @@ fn parse_input1<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
         }
     }
 
-    Some(problems)
+    Ok(problems)
 }
 
-fn parse_input2<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
+pub fn parse_input2<S: AsRef<str>>(input_data_: S) -> Result<Vec<Problem>, ParseError> {
     let input_data = input_data_.as_ref();
     let mut transposed_input_data = Vec::new();
 
@@ -111,9 +126,7 @@ fn parse_input2<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
             }
         } else {
             for op_str in line.split_ascii_whitespace() {
-                let Ok(op) = op_str.try_into() else {
-                    return None;
-                };
+                let op = MathOp::try_from(op_str).map_err(|err| err.within(input_data, op_str))?;
                 problems.push(Problem {
                     inputs: smallvec!(),
                     op: Some(op),
@@ -123,56 +136,107 @@ fn parse_input2<S: AsRef<str>>(input_data_: S) -> Option<Vec<Problem>> {
     }
 
     let mut idx = 0;
-    for line in transposed_input_data {
+    for (column, line) in transposed_input_data.iter().enumerate() {
         if line.trim() == "" {
             idx += 1;
             continue;
         }
         for split in line.split_ascii_whitespace() {
+            // the transposed line is a column of the input, so its columns are the rows
+            let error = |expected: &str| {
+                let row = ParseError::at(line, split, "").column;
+                ParseError::new(row, column + 1, split, expected)
+            };
             let Ok(num) = split.parse() else {
-                return None;
+                return Err(error("a number"));
             };
-            problems[idx].inputs.push(num);
+            let Some(problem) = problems.get_mut(idx) else {
+                return Err(error("a number above an operator"));
+            };
+            problem.inputs.push(num);
+        }
+    }
+
+    Ok(problems)
+}
+
+/// A random worksheet with `size` problems of the same number of numbers. The numbers of a problem
+/// are aligned either to the left or to the right, like in the puzzle.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let num_rows = rng.range(2..=4) as usize;
+    let mut lines = vec![String::new(); num_rows + 1];
+
+    for problem_idx in 0..size {
+        let width = rng.range(1..=4) as usize;
+        let right_aligned = rng.chance(0.5);
+        // one number spans the whole problem, so that every column of it contains a digit
+        let widest_row = rng.index(num_rows);
+
+        for (row, line) in lines[..num_rows].iter_mut().enumerate() {
+            let num_digits = if row == widest_row {
+                width as u32
+            } else {
+                rng.range(1..=width as u64) as u32
+            };
+            let num = rng.range(10u64.pow(num_digits - 1)..=10u64.pow(num_digits) - 1);
+            if problem_idx > 0 {
+                line.push(' ');
+            }
+            if right_aligned {
+                line.push_str(&format!("{num:>width$}"));
+            } else {
+                line.push_str(&format!("{num:<width$}"));
+            }
+        }
+
+        let op = if rng.chance(0.5) { '+' } else { '*' };
+        if problem_idx > 0 {
+            lines[num_rows].push(' ');
         }
+        lines[num_rows].push_str(&format!("{op:<width$}"));
     }
 
-    Some(problems)
+    lines.join("\n")
 }
 
-fn solve_problems(problems: &[Problem]) -> impl Iterator<Item = u64> {
+pub fn solve_problems(problems: &[Problem]) -> impl Iterator<Item = Option<u64>> {
     problems.iter().map(Problem::solve)
 }
 
-pub fn solve_day06(input_data: String) {
-    let input1 = match parse_input1(&input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input1!");
-            return;
-        }
-    };
+fn sum_problems(problems: &[Problem]) -> Result<Answer, SolveError> {
+    solve_problems(problems)
+        .sum::<Option<u64>>()
+        .ok_or_else(|| SolveError::new("every problem needs an operator"))
+}
 
-    let input2 = match parse_input2(&input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input2!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1: u64 = solve_problems(&input1).sum();
-    let end_1 = Instant::now();
-    println!(
-        "Task 1:\t\t{sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2: u64 = solve_problems(&input2).sum();
-    let end_2 = Instant::now();
-    println!(
-        "Task 2:\t\t{sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+/// The worksheet read in both ways: row-wise for task 1 and column-wise for task 2.
+pub struct Worksheet {
+    pub rows: Vec<Problem>,
+    pub columns: Vec<Problem>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Worksheet;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            rows: parse_input1(input_data)?,
+            columns: parse_input2(input_data)?,
+        })
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        sum_problems(&input.rows)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        sum_problems(&input.columns)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
@@ -1,48 +1,120 @@
-use std::{ops::Not, time::Instant};
+use std::ops::Not;
 
 use bitvec::prelude::*;
 
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
+/// The splitters of the manifold, row by row.
 #[derive(Debug, Clone)]
-struct Grid {
-    data: BitVec,
-    width: usize,
-    height: usize,
+pub struct Grid {
+    pub data: BitVec,
+    pub width: usize,
+    pub height: usize,
 }
 
-struct Manifold {
-    start: usize,
-    grid: Grid,
+pub struct Manifold {
+    pub start: usize,
+    pub grid: Grid,
 }
 
-fn parse_input(input_data: String) -> Option<Manifold> {
+pub fn parse_input(input_data: &str) -> Result<Manifold, ParseError> {
     let mut lines = input_data.lines();
     let Some(first_line) = lines.next() else {
-        return None;
+        return Err(ParseError::at(input_data, input_data, "a manifold"));
     };
 
     let Some(start) = first_line.find("S") else {
-        return None;
+        return Err(ParseError::at(
+            input_data,
+            first_line,
+            "a first line containing the start (S)",
+        ));
     };
 
+    if let Some((idx, c)) = first_line
+        .char_indices()
+        .find(|&(_, c)| c != '.' && c != 'S')
+    {
+        return Err(ParseError::at(
+            input_data,
+            &first_line[idx..idx + c.len_utf8()],
+            "'.' or 'S'",
+        ));
+    }
+
+    // the first line has no splitters
     let width = first_line.len();
-    let mut height = 1;
-    let grid_data: BitVec = vec![false; width]
-        .into_iter()
-        .chain(lines.flat_map(|line| {
-            height += 1;
-            line.chars().map(|c| c == '^')
-        }))
-        .collect();
+    let mut grid_data: BitVec = BitVec::repeat(false, width);
+    for line in lines {
+        if line.len() != width {
+            return Err(ParseError::at(
+                input_data,
+                line,
+                format!("a line with {width} cells"),
+            ));
+        }
+        for (idx, c) in line.char_indices() {
+            match c {
+                '.' => grid_data.push(false),
+                '^' => grid_data.push(true),
+                _ => {
+                    return Err(ParseError::at(
+                        input_data,
+                        &line[idx..idx + c.len_utf8()],
+                        "'.' or '^'",
+                    ));
+                }
+            }
+        }
+    }
 
+    let height = grid_data.len() / width;
     let grid = Grid {
         data: grid_data,
         width,
         height,
     };
-    Some(Manifold { start, grid })
+    Ok(Manifold { start, grid })
+}
+
+/// A random manifold with `size` rows of splitters below each other, which lie in the cone of
+/// cells the beam can reach. The number of timelines grows exponentially with the rows, so there
+/// are at most 80 rows of splitters to keep it within the answer.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let size = size.min(80);
+    let width = 2 * size + 1;
+    let start = size;
+
+    let mut lines = vec![format!(
+        "{}S{}",
+        ".".repeat(start),
+        ".".repeat(width - start - 1)
+    )];
+    for row in 0..size {
+        lines.push(".".repeat(width));
+        let line = (0..width)
+            .map(|x| {
+                let in_cone = x.abs_diff(start) <= row && (x + row - start).is_multiple_of(2);
+                if in_cone && (row == 0 || rng.chance(0.7)) {
+                    '^'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.push(".".repeat(width));
+
+    lines.join("\n")
 }
 
-fn num_beam_splits(manifold: &Manifold) -> u64 {
+pub fn num_beam_splits(manifold: &Manifold) -> u64 {
     let mut num_splits = 0;
 
     let mut state: BitVec<usize, Lsb0> = BitVec::repeat(false, manifold.grid.width);
@@ -75,7 +147,7 @@ fn num_beam_splits(manifold: &Manifold) -> u64 {
     num_splits
 }
 
-fn num_timelines(manifold: &Manifold) -> u64 {
+pub fn num_timelines(manifold: &Manifold) -> u64 {
     let mut state = vec![0; manifold.grid.width];
     state[manifold.start] = 1;
 
@@ -110,28 +182,25 @@ fn num_timelines(manifold: &Manifold) -> u64 {
     state.into_iter().sum()
 }
 
-pub fn solve_day07(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Manifold;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Ok(num_beam_splits(input))
+    }
 
-    let start_1 = Instant::now();
-    let sol_task1 = num_beam_splits(&input);
-    let end_1 = Instant::now();
-    println!(
-        "Task 1:\t{sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2 = num_timelines(&input);
-    let end_2 = Instant::now();
-    println!(
-        "Task 2:\t{sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Ok(num_timelines(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
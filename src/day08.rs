@@ -1,14 +1,22 @@
-use std::{cmp::Ordering, collections::BinaryHeap, time::Instant};
+use std::{cmp::Ordering, collections::BinaryHeap};
 
 use bitvec::order::Lsb0;
 use bitvec::vec::BitVec;
 use petgraph::algo::kosaraju_scc;
 use petgraph::{Graph, graph::NodeIndex};
 
-struct Position {
-    x: u32,
-    y: u32,
-    z: u32,
+use crate::{
+    generate::Rng,
+    params::{Param, ParamKind, Params},
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError, Variant},
+};
+
+#[derive(Debug)]
+pub struct Position {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
 }
 
 impl Position {
@@ -21,31 +29,48 @@ impl Position {
 }
 
 impl TryFrom<&str> for Position {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut nums_iter = value.split(",").map(str::parse).map(Result::ok);
-        let Some(x) = nums_iter.next().flatten() else {
-            return Err(());
-        };
-        let Some(y) = nums_iter.next().flatten() else {
-            return Err(());
-        };
-        let Some(z) = nums_iter.next().flatten() else {
-            return Err(());
+        let mut nums_iter = value.split(",");
+        let mut next_coordinate = |expected: &str| {
+            let Some(num_str) = nums_iter.next() else {
+                return Err(ParseError::at(value, &value[value.len()..], expected));
+            };
+            num_str
+                .parse()
+                .map_err(|_| ParseError::at(value, num_str, expected))
         };
+        let x = next_coordinate("an x coordinate")?;
+        let y = next_coordinate("a y coordinate")?;
+        let z = next_coordinate("a z coordinate")?;
+        if let Some(extra_str) = nums_iter.next() {
+            return Err(ParseError::at(value, extra_str, "the end of the line"));
+        }
         Ok(Position { x, y, z })
     }
 }
 
-fn parse_input(input_data: String) -> Option<Vec<Position>> {
+pub fn parse_input(input_data: &str) -> Result<Vec<Position>, ParseError> {
     input_data
         .lines()
-        .map(Position::try_from)
-        .map(Result::ok)
+        .map(|line| Position::try_from(line).map_err(|err| err.within(input_data, line)))
         .collect()
 }
 
+/// `size` random junction boxes, but at least two, as task 2 needs a pair to connect.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| {
+            let x = rng.range(0..=99_999);
+            let y = rng.range(0..=99_999);
+            let z = rng.range(0..=99_999);
+            format!("{x},{y},{z}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 struct DistElem<const REV_ORD: bool> {
     distance: u64,
     a: usize,
@@ -79,7 +104,10 @@ impl<const REV_ORD: bool> Ord for DistElem<REV_ORD> {
     }
 }
 
-fn solve_task1(boxes: &Vec<Position>, num_pairs: usize) -> usize {
+/// Product of the sizes of the three largest circuits after connecting the `num_pairs` closest
+/// pairs, or of all circuits if there are less than three. If there are less pairs, all of them
+/// are connected.
+pub fn solve_task1(boxes: &Vec<Position>, num_pairs: usize) -> usize {
     let mut dist_heap = (0..boxes.len())
         .flat_map(|i| {
             let a = &boxes[i];
@@ -118,7 +146,8 @@ fn solve_task1(boxes: &Vec<Position>, num_pairs: usize) -> usize {
     conn_comp.iter().take(3).map(Vec::len).product()
 }
 
-fn solve_task1_smart(boxes: &Vec<Position>, num_pairs: usize) -> usize {
+/// Same as [`solve_task1`], but with a stripped down union-find instead of a graph.
+pub fn solve_task1_smart(boxes: &Vec<Position>, num_pairs: usize) -> usize {
     let mut ccs = (0..boxes.len())
         .map(|i| {
             let mut bitvec = BitVec::<usize, Lsb0>::with_capacity(boxes.len());
@@ -194,10 +223,16 @@ fn solve_task1_smart(boxes: &Vec<Position>, num_pairs: usize) -> usize {
         }
     }
 
-    max1 * max2 * max3
+    // circuits that do not exist must not zero the product
+    [max1, max2, max3]
+        .into_iter()
+        .filter(|&len| len > 0)
+        .product()
 }
 
-fn solve_task2(boxes: &Vec<Position>) -> u32 {
+/// Product of the x coordinates of the last pair that connects all boxes, `None` if there are less
+/// than two boxes.
+pub fn solve_task2(boxes: &Vec<Position>) -> Option<u64> {
     let mut dist_heap = (0..boxes.len())
         .flat_map(|i| {
             let a = &boxes[i];
@@ -219,21 +254,21 @@ fn solve_task2(boxes: &Vec<Position>) -> u32 {
         assert_eq!(idx, node_idx.index());
     }
 
-    assert!(kosaraju_scc(&graph).len() > 2);
     while let Some(min_edge) = dist_heap.pop() {
         let a_node = NodeIndex::new(min_edge.a);
         let b_node = NodeIndex::new(min_edge.b);
 
         graph.add_edge(a_node, b_node, ());
         if kosaraju_scc(&graph).len() == 1 {
-            return boxes[min_edge.a].x * boxes[min_edge.b].x;
+            return Some(u64::from(boxes[min_edge.a].x) * u64::from(boxes[min_edge.b].x));
         }
     }
 
-    unreachable!()
+    None
 }
 
-fn solve_task2_smart(boxes: &Vec<Position>) -> u32 {
+/// Same as [`solve_task2`], but with a stripped down union-find instead of a graph.
+pub fn solve_task2_smart(boxes: &Vec<Position>) -> Option<u64> {
     let mut ccs = (0..boxes.len())
         .map(|i| {
             let mut bitvec = BitVec::<usize, Lsb0>::with_capacity(boxes.len());
@@ -288,51 +323,56 @@ fn solve_task2_smart(boxes: &Vec<Position>) -> u32 {
         }
 
         if ccs.len() == 1 {
-            return boxes[edge.a].x * boxes[edge.b].x;
+            return Some(u64::from(boxes[edge.a].x) * u64::from(boxes[edge.b].x));
         }
     }
 
-    unreachable!()
+    None
 }
 
-pub fn solve_day08(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1 = solve_task1(&input, 1000);
-    let end_1 = Instant::now();
-    println!(
-        "Task 1:\t\t{sol_task1}\t\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_1_smart = Instant::now();
-    let sol_task1_smart = solve_task1_smart(&input, 1000);
-    let end_1_smart = Instant::now();
-    println!(
-        "Smart 1:\t{sol_task1_smart}\t\tTook: {}µs",
-        (end_1_smart - start_1_smart).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2 = solve_task2(&input);
-    let end_2 = Instant::now();
-    println!(
-        "Task 2:\t\t{sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
-
-    let start_2_smart = Instant::now();
-    let sol_task2_smart = solve_task2_smart(&input);
-    let end_2_smart = Instant::now();
-    println!(
-        "Smart 2:\t{sol_task2_smart}\tTook: {}µs",
-        (end_2_smart - start_2_smart).as_micros()
-    );
+fn task2_answer(answer: Option<u64>) -> Result<Answer, SolveError> {
+    answer.ok_or_else(|| SolveError::new("at least two junction boxes are needed"))
+}
+
+pub const PAIRS: Param = Param {
+    name: "pairs",
+    kind: ParamKind::Number,
+    default: "1000",
+    description: "number of closest pairs of junction boxes that are connected in task 1",
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Vec<Position>;
+
+    const PARAMS: &'static [Param] = &[PAIRS];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        Ok(solve_task1_smart(input, params.get(&PAIRS)?) as Answer)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        task2_answer(solve_task2_smart(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, "smart", Self::part1),
+            Variant::new(1, "petgraph", |input, params| {
+                Ok(solve_task1(input, params.get(&PAIRS)?) as Answer)
+            }),
+            Variant::new(2, "smart", Self::part2),
+            Variant::new(2, "petgraph", |input, _| task2_answer(solve_task2(input))),
+        ]
+    }
 }
@@ -1,9 +1,16 @@
-use std::{fmt::Display, time::Instant};
+use std::fmt::Display;
+
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
 
 #[derive(Debug, Clone, Copy)]
-struct Point2D {
-    x: u64,
-    y: u64,
+pub struct Point2D {
+    pub x: u64,
+    pub y: u64,
 }
 
 impl Point2D {
@@ -19,21 +26,17 @@ impl Point2D {
 }
 
 impl TryFrom<&str> for Point2D {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut values = value.split(",");
-        let Some(x_str) = values.next() else {
-            return Err(());
-        };
-        let Some(y_str) = values.next() else {
-            return Err(());
+        let Some((x_str, y_str)) = value.split_once(",") else {
+            return Err(ParseError::at(value, value, "a point (x,y)"));
         };
         let Ok(x) = x_str.parse() else {
-            return Err(());
+            return Err(ParseError::at(value, x_str, "an x coordinate"));
         };
         let Ok(y) = y_str.parse() else {
-            return Err(());
+            return Err(ParseError::at(value, y_str, "a y coordinate"));
         };
         Ok(Point2D { x, y })
     }
@@ -45,17 +48,70 @@ impl Display for Point2D {
     }
 }
 
-#[must_use]
-fn parse_input(input_data: String) -> Option<Vec<Point2D>> {
+pub fn parse_input(input_data: &str) -> Result<Vec<Point2D>, ParseError> {
     input_data
         .lines()
-        .map(Point2D::try_from)
-        .map(Result::ok)
+        .map(|line| Point2D::try_from(line).map_err(|err| err.within(input_data, line)))
         .collect()
 }
 
+/// A random closed polygon of `size` red tiles (rounded down to a multiple of 4, but at least 4),
+/// whose neighbouring tiles share a row or a column. The polygon is a strip of columns, which have
+/// a random top in the upper and a random bottom in the lower half.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    const MAX: u64 = 99_999;
+    const MID: u64 = MAX / 2;
+
+    let num_columns = (size / 4).max(1);
+    let step = (MAX / (num_columns as u64 + 1)).max(1);
+
+    let mut xs = vec![rng.range(0..=step)];
+    for _ in 0..num_columns {
+        let last = xs[xs.len() - 1];
+        xs.push(last + rng.range(1..=step));
+    }
+
+    // neighbouring columns need different tops and bottoms, otherwise their tiles would not be
+    // corners of the polygon
+    let mut tops: Vec<u64> = Vec::with_capacity(num_columns);
+    let mut bottoms: Vec<u64> = Vec::with_capacity(num_columns);
+    for _ in 0..num_columns {
+        let top = loop {
+            let top = rng.range(MID + 1..=MAX);
+            if tops.last() != Some(&top) {
+                break top;
+            }
+        };
+        let bottom = loop {
+            let bottom = rng.range(0..=MID - 1);
+            if bottoms.last() != Some(&bottom) {
+                break bottom;
+            }
+        };
+        tops.push(top);
+        bottoms.push(bottom);
+    }
+
+    let mut tiles = Vec::with_capacity(4 * num_columns);
+    for column in 0..num_columns {
+        tiles.push((xs[column], tops[column]));
+        tiles.push((xs[column + 1], tops[column]));
+    }
+    for column in (0..num_columns).rev() {
+        tiles.push((xs[column + 1], bottoms[column]));
+        tiles.push((xs[column], bottoms[column]));
+    }
+
+    tiles
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The largest rectangle spanned by two of the points, `None` if there are less than two points.
 #[must_use]
-fn largest_rec(points: &[Point2D]) -> u64 {
+pub fn largest_rec(points: &[Point2D]) -> Option<u64> {
     (0..points.len())
         .flat_map(|i| ((i + 1)..points.len()).map(move |j| (i, j)))
         .map(|(i, j)| {
@@ -64,11 +120,10 @@ fn largest_rec(points: &[Point2D]) -> u64 {
             Point2D::area(a, b)
         })
         .max()
-        .unwrap()
 }
 
 #[must_use]
-fn solve_task2(points: &[Point2D]) -> u64 {
+pub fn solve_task2(points: &[Point2D]) -> u64 {
     let mut max_area = 0;
     for i in 0..points.len() {
         let a = &points[i];
@@ -169,30 +224,25 @@ fn print_grid(points: &[Point2D]) {
     advance_to(width * height, false);
 }
 
-pub fn solve_day09(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Point2D>;
 
-    let start_1 = Instant::now();
-    let sol_task1 = largest_rec(&input);
-    let end_1 = Instant::now();
-    println!(
-        "Task 1: {sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2 = solve_task2(&input);
-    let end_2 = Instant::now();
-    println!(
-        "Task 2: {sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
-
-    // print_grid(&input);
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        largest_rec(input).ok_or_else(|| SolveError::new("at least two red tiles are needed"))
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Ok(solve_task2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
@@ -1,80 +1,114 @@
-use std::{fmt::Display, iter, time::Instant};
+use std::{fmt::Display, iter};
 
 use bitvec::prelude::*;
 use z3::{Optimize, SatResult, ast::Int};
 
-struct Machine {
-    indicator_lights: BitVec,
-    button_wiring: Vec<BitVec>,
-    joltage_req: Vec<u16>,
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
+pub struct Machine {
+    pub indicator_lights: BitVec,
+    pub button_wiring: Vec<BitVec>,
+    pub joltage_req: Vec<u16>,
 }
 
 impl TryFrom<&str> for Machine {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let Some(indicator_light_start) = value.find("[") else {
-            return Err(());
-        };
-        let Some(indicator_light_end) = value.find("]") else {
-            return Err(());
-        };
-        let Some(wiring_start) = value.find("(") else {
-            return Err(());
+        // every section is searched behind the previous one, so that they are in order
+        let find = |pattern: &str, from: usize, expected: &str| {
+            value[from..]
+                .find(pattern)
+                .map(|idx| from + idx)
+                .ok_or_else(|| ParseError::at(value, &value[from..], expected))
         };
-        let Some(wiring_end) = value.rfind(")") else {
-            return Err(());
-        };
-        let Some(joltage_req_start) = value.find("{") else {
-            return Err(());
-        };
-        let Some(joltage_req_end) = value.find("}") else {
-            return Err(());
+        let indicator_light_start = find("[", 0, "an indicator light diagram ([...])")?;
+        let indicator_light_end = find(
+            "]",
+            indicator_light_start + 1,
+            "the end of the indicator light diagram (])",
+        )?;
+        let wiring_start = find(
+            "(",
+            indicator_light_end + 1,
+            "a button wiring schematic ((...))",
+        )?;
+        let joltage_req_start = find("{", wiring_start + 1, "joltage requirements ({...})")?;
+        let Some(wiring_end) = value[..joltage_req_start]
+            .rfind(")")
+            .filter(|&idx| idx > wiring_start)
+        else {
+            return Err(ParseError::at(
+                value,
+                &value[wiring_start..joltage_req_start],
+                "the end of the button wiring schematics ())",
+            ));
         };
+        let joltage_req_end = find(
+            "}",
+            joltage_req_start + 1,
+            "the end of the joltage requirements (})",
+        )?;
         if joltage_req_end + 1 != value.len() {
-            return Err(());
+            return Err(ParseError::at(
+                value,
+                &value[joltage_req_end + 1..],
+                "the end of the line",
+            ));
         }
 
         let mut indicator_lights: BitVec<usize, Lsb0> = BitVec::new();
-        for c in value[indicator_light_start + 1..indicator_light_end].chars() {
+        let indicator_lights_str = &value[indicator_light_start + 1..indicator_light_end];
+        for (idx, c) in indicator_lights_str.char_indices() {
             let v = match c {
                 '.' => false,
                 '#' => true,
-                _ => return Err(()),
+                _ => {
+                    return Err(ParseError::at(
+                        value,
+                        &indicator_lights_str[idx..idx + c.len_utf8()],
+                        "'.' or '#'",
+                    ));
+                }
             };
             indicator_lights.push(v);
         }
 
-        let Some(button_wiring) = value[(wiring_start + 1)..wiring_end]
+        let button_wiring = value[(wiring_start + 1)..wiring_end]
             .split(") (")
             .map(|button| {
-                let Some(toggled_lights) = button
+                let toggled_lights = button
                     .split(",")
-                    .map(|idx_str| idx_str.parse())
-                    .map(Result::ok)
-                    .collect::<Option<Vec<usize>>>()
-                else {
-                    return None;
-                };
+                    .map(|idx_str| match idx_str.parse() {
+                        Ok(idx) if idx < indicator_lights.len() => Ok(idx),
+                        _ => Err(ParseError::at(
+                            value,
+                            idx_str,
+                            format!("a light index below {}", indicator_lights.len()),
+                        )),
+                    })
+                    .collect::<Result<Vec<usize>, _>>()?;
                 let mut wiring: BitVec<usize, Lsb0> = BitVec::repeat(false, indicator_lights.len());
                 for light_idx in toggled_lights {
                     wiring.set(light_idx, true);
                 }
-                Some(wiring)
+                Ok(wiring)
             })
-            .collect()
-        else {
-            return Err(());
-        };
+            .collect::<Result<_, _>>()?;
 
-        let Some(joltage_req) = value[(joltage_req_start + 1)..joltage_req_end]
+        let joltage_req = value[(joltage_req_start + 1)..joltage_req_end]
             .split(",")
-            .map(|num_str| num_str.parse())
-            .map(Result::ok)
-            .collect()
-        else {
-            return Err(());
-        };
+            .map(|num_str| {
+                num_str
+                    .parse()
+                    .map_err(|_| ParseError::at(value, num_str, "a joltage"))
+            })
+            .collect::<Result<_, _>>()?;
 
         Ok(Machine {
             indicator_lights,
@@ -94,19 +128,79 @@ impl Display for Machine {
     }
 }
 
-#[must_use]
-fn parse_input(input_data: String) -> Option<Vec<Machine>> {
+pub fn parse_input(input_data: &str) -> Result<Vec<Machine>, ParseError> {
     input_data
         .lines()
-        .map(Machine::try_from)
-        .map(Result::ok)
+        .map(|line| Machine::try_from(line).map_err(|err| err.within(input_data, line)))
         .collect()
 }
 
-fn min_button_presses_light(machines: &[Machine]) -> u32 {
+/// `size` random machines. The indicator lights and the joltage requirements are generated by
+/// pressing random buttons, so every machine can be configured.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let num_lights = rng.range(3..=10) as usize;
+            let num_buttons = rng.range(2..=(num_lights as u64 + 3).min(13)) as usize;
+
+            let buttons = (0..num_buttons)
+                .map(|_| {
+                    let mut lights = (0..num_lights)
+                        .filter(|_| rng.chance(0.4))
+                        .collect::<Vec<_>>();
+                    if lights.is_empty() {
+                        lights.push(rng.index(num_lights));
+                    }
+                    lights
+                })
+                .collect::<Vec<_>>();
+
+            let mut indicator_lights = vec![false; num_lights];
+            let mut joltage_req = vec![0; num_lights];
+            for button in &buttons {
+                let toggled = rng.chance(0.5);
+                let presses = rng.range(0..=20);
+                for &light in button {
+                    indicator_lights[light] ^= toggled;
+                    joltage_req[light] += presses;
+                }
+            }
+
+            let indicator_lights = indicator_lights
+                .iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect::<String>();
+            let buttons = buttons
+                .iter()
+                .map(|lights| {
+                    let lights = lights.iter().map(usize::to_string).collect::<Vec<_>>();
+                    format!("({})", lights.join(","))
+                })
+                .collect::<Vec<_>>();
+            let joltage_req = joltage_req.iter().map(u64::to_string).collect::<Vec<_>>();
+
+            format!(
+                "[{indicator_lights}] {} {{{}}}",
+                buttons.join(" "),
+                joltage_req.join(",")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn min_button_presses_light(machines: &[Machine]) -> Result<u32, SolveError> {
     machines
         .iter()
-        .map(|machine| {
+        .enumerate()
+        .map(|(machine_idx, machine)| {
+            if machine.button_wiring.len() >= 64 {
+                return Err(SolveError::new(format!(
+                    "machine {} has more than 63 buttons",
+                    machine_idx + 1
+                )));
+            }
+
             (0..(1u64 << machine.button_wiring.len()))
                 .filter_map(|mut button_pattern| {
                     let num_buttons = button_pattern.count_ones();
@@ -129,16 +223,27 @@ fn min_button_presses_light(machines: &[Machine]) -> u32 {
                     }
                 })
                 .min()
-                .unwrap()
+                .ok_or_else(|| {
+                    SolveError::new(format!(
+                        "the indicator lights of machine {} cannot be configured",
+                        machine_idx + 1
+                    ))
+                })
         })
         .sum()
 }
 
-fn min_button_presses_joltage(machines: &[Machine]) -> u64 {
+pub fn min_button_presses_joltage(machines: &[Machine]) -> Result<u64, SolveError> {
     machines
         .iter()
-        .map(|machine| {
-            assert!(machine.button_wiring.len() < 32);
+        .enumerate()
+        .map(|(machine_idx, machine)| {
+            let unsolvable = || {
+                SolveError::new(format!(
+                    "the joltage requirements of machine {} cannot be met",
+                    machine_idx + 1
+                ))
+            };
             let optimizer = Optimize::new();
 
             let count_vars = (0..machine.joltage_req.len())
@@ -164,7 +269,7 @@ fn min_button_presses_joltage(machines: &[Machine]) -> u64 {
                         .button_wiring
                         .iter()
                         .enumerate()
-                        .filter(|(_, wiring)| *wiring.get(count_idx).unwrap())
+                        .filter(|(_, wiring)| wiring.get(count_idx).is_some_and(|bit| *bit))
                         .map(|(button_idx, _)| &button_presses[button_idx])
                         .collect::<Vec<_>>(),
                 );
@@ -174,40 +279,37 @@ fn min_button_presses_joltage(machines: &[Machine]) -> u64 {
             let num_button_presses = Int::add(&button_presses);
             optimizer.minimize(&num_button_presses);
 
-            assert_eq!(optimizer.check(&[]), SatResult::Sat);
+            if optimizer.check(&[]) != SatResult::Sat {
+                return Err(unsolvable());
+            }
             optimizer
                 .get_model()
-                .unwrap()
-                .eval(&num_button_presses, false)
-                .unwrap()
-                .as_u64()
-                .unwrap()
+                .and_then(|model| model.eval(&num_button_presses, false))
+                .and_then(|presses| presses.as_u64())
+                .ok_or_else(unsolvable)
         })
         .sum()
 }
 
-pub fn solve_day10(input_data: String) {
-    let input = match parse_input(input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
-
-    let start_1 = Instant::now();
-    let sol_task1 = min_button_presses_light(&input);
-    let end_1 = Instant::now();
-    println!(
-        "Task 1: {sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2 = min_button_presses_joltage(&input);
-    let end_2 = Instant::now();
-    println!(
-        "Task 2: {sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Machine>;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        min_button_presses_light(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        min_button_presses_joltage(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
@@ -1,29 +1,41 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::{HashMap, HashSet};
 
-struct Input<'a> {
-    devices: Vec<Vec<usize>>,
-    device_map: HashMap<&'a str, usize>,
+use crate::{
+    generate::Rng,
+    params::{Param, ParamKind, Params},
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
+pub struct Input {
+    /// The indices of the devices every device is connected to.
+    pub devices: Vec<Vec<usize>>,
+    /// The index of every device by its name.
+    pub device_map: HashMap<String, usize>,
 }
 
-#[must_use]
-fn parse_input<'a>(input_data: &'a str) -> Option<Input<'a>> {
+pub fn parse_input(input_data: &str) -> Result<Input, ParseError> {
     let mut devices = Vec::new();
     let mut device_map = HashMap::new();
 
     for line in input_data.lines() {
-        let mut splits = line.split(": ");
-        let Some(device_name) = splits.next() else {
-            return None;
-        };
-        let Some(attached_str) = splits.next() else {
-            return None;
+        let Some((device_name, attached_str)) = line.split_once(": ") else {
+            return Err(ParseError::at(
+                input_data,
+                line,
+                "a device name followed by ': '",
+            ));
         };
-        if splits.next().is_some() {
-            return None;
+        if let Some(idx) = attached_str.find(": ") {
+            return Err(ParseError::at(
+                input_data,
+                &attached_str[idx..],
+                "the end of the line",
+            ));
         }
 
         if !device_map.contains_key(device_name) {
-            device_map.insert(device_name, devices.len());
+            device_map.insert(device_name.to_string(), devices.len());
             devices.push(Vec::new());
         }
 
@@ -32,7 +44,7 @@ fn parse_input<'a>(input_data: &'a str) -> Option<Input<'a>> {
         let attached = attached_str
             .split_ascii_whitespace()
             .map(|device| {
-                let attached_idx = *device_map.entry(device).or_insert_with(|| {
+                let attached_idx = *device_map.entry(device.to_string()).or_insert_with(|| {
                     let attached_idx = devices.len();
                     devices.push(Vec::new());
 
@@ -43,16 +55,80 @@ fn parse_input<'a>(input_data: &'a str) -> Option<Input<'a>> {
             .collect::<Vec<_>>();
 
         let device = &mut devices[device_idx];
-        assert!(device.is_empty());
+        if !device.is_empty() {
+            return Err(ParseError::at(
+                input_data,
+                device_name,
+                "a device that is not listed before",
+            ));
+        }
         *device = attached;
     }
 
-    Some(Input {
+    Ok(Input {
         devices,
         device_map,
     })
 }
 
+/// A random acyclic graph of the devices of the puzzle and `size` other devices. Every device is
+/// attached to the next one in a random order, so all paths of the puzzle exist. Only a limited
+/// number of devices are attached to a second device, which bounds the number of paths: there are
+/// at most 2^16 paths from `you`, which are enumerated one by one in task 1, and 2^40 in total.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    const DEVICES: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+    const BRANCHES_BEFORE_YOU: usize = 24;
+    const BRANCHES_AFTER_YOU: usize = 16;
+
+    let size = size.min(26usize.pow(3) - DEVICES.len());
+    let mut names = Vec::with_capacity(size + DEVICES.len());
+    let mut used = HashSet::from(DEVICES.map(String::from));
+    while names.len() < size {
+        let name = (0..3)
+            .map(|_| char::from(b'a' + rng.range(0..=25) as u8))
+            .collect::<String>();
+        if used.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names.insert(0, String::from("svr"));
+    for device in ["you", "fft", "dac"] {
+        let idx = rng.range(1..=names.len() as u64) as usize;
+        names.insert(idx, String::from(device));
+    }
+    names.push(String::from("out"));
+
+    let you_idx = names
+        .iter()
+        .position(|name| name == "you")
+        .unwrap_or_default();
+    let mut pick_branches = |candidates: std::ops::Range<usize>, max_branches: usize| {
+        let mut candidates = candidates.collect::<Vec<_>>();
+        rng.shuffle(&mut candidates);
+        candidates.truncate(max_branches);
+        candidates
+    };
+    // the second attached device has to be after the next one
+    let last_branching = names.len().saturating_sub(2);
+    let mut branches = pick_branches(0..you_idx.min(last_branching), BRANCHES_BEFORE_YOU);
+    branches.extend(pick_branches(you_idx..last_branching, BRANCHES_AFTER_YOU));
+
+    let mut lines = (0..names.len() - 1)
+        .map(|idx| {
+            let mut attached = vec![names[idx + 1].as_str()];
+            if branches.contains(&idx) {
+                let second = rng.range(idx as u64 + 2..=names.len() as u64 - 1) as usize;
+                attached.push(&names[second]);
+            }
+            format!("{}: {}", names[idx], attached.join(" "))
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+
+    lines.join("\n")
+}
+
 fn find_paths_naive_rec(start_idx: usize, end_idx: usize, input: &Input) -> usize {
     if start_idx == end_idx {
         return 1;
@@ -63,10 +139,18 @@ fn find_paths_naive_rec(start_idx: usize, end_idx: usize, input: &Input) -> usiz
         .sum()
 }
 
-fn find_paths_naive(start: &str, end: &str, input: &Input) -> usize {
-    let start_idx = input.device_map.get(start).expect("Not found start");
-    let end_idx = input.device_map.get(end).expect("Not found end");
-    find_paths_naive_rec(*start_idx, *end_idx, input)
+pub fn device_idx(name: &str, input: &Input) -> Result<usize, SolveError> {
+    input
+        .device_map
+        .get(name)
+        .copied()
+        .ok_or_else(|| SolveError::new(format!("there is no device \"{name}\"")))
+}
+
+pub fn find_paths_naive(start: &str, end: &str, input: &Input) -> Result<usize, SolveError> {
+    let start_idx = device_idx(start, input)?;
+    let end_idx = device_idx(end, input)?;
+    Ok(find_paths_naive_rec(start_idx, end_idx, input))
 }
 
 fn find_paths_rec(
@@ -91,55 +175,96 @@ fn find_paths_rec(
     num_paths
 }
 
-fn find_paths_memo(start_idx: usize, end_idx: usize, input: &Input) -> usize {
+/// Number of paths between the devices with the given indices, see [`device_idx`].
+pub fn find_paths_memo(start_idx: usize, end_idx: usize, input: &Input) -> usize {
     let mut memo = HashMap::new();
     find_paths_rec(start_idx, end_idx, input, &mut memo)
 }
 
-fn find_paths_with_stop(
+pub fn find_paths_with_stop(
     start: &str,
     stop_a: &str,
     stop_b: &str,
     end: &str,
     input: &Input,
-) -> usize {
-    let start_idx = input.device_map.get(start).expect("Not found start");
-    let stop_a_idx = input.device_map.get(stop_a).expect("Not found stop_a");
-    let stop_b_idx = input.device_map.get(stop_b).expect("Not found stop_b");
-    let end_idx = input.device_map.get(end).expect("Not found end");
-
-    let num_paths_a = find_paths_memo(*start_idx, *stop_a_idx, input)
-        * find_paths_memo(*stop_a_idx, *stop_b_idx, input)
-        * find_paths_memo(*stop_b_idx, *end_idx, input);
-    let num_paths_b = find_paths_memo(*start_idx, *stop_b_idx, input)
-        * find_paths_memo(*stop_b_idx, *stop_a_idx, input)
-        * find_paths_memo(*stop_a_idx, *end_idx, input);
-
-    num_paths_a + num_paths_b
+) -> Result<usize, SolveError> {
+    let start_idx = device_idx(start, input)?;
+    let stop_a_idx = device_idx(stop_a, input)?;
+    let stop_b_idx = device_idx(stop_b, input)?;
+    let end_idx = device_idx(end, input)?;
+
+    let num_paths_a = find_paths_memo(start_idx, stop_a_idx, input)
+        * find_paths_memo(stop_a_idx, stop_b_idx, input)
+        * find_paths_memo(stop_b_idx, end_idx, input);
+    let num_paths_b = find_paths_memo(start_idx, stop_b_idx, input)
+        * find_paths_memo(stop_b_idx, stop_a_idx, input)
+        * find_paths_memo(stop_a_idx, end_idx, input);
+
+    Ok(num_paths_a + num_paths_b)
 }
 
-pub fn solve_day11(input_data: String) {
-    let input = match parse_input(&input_data) {
-        Some(input) => input,
-        None => {
-            eprintln!("Could not parse input!");
-            return;
-        }
-    };
+pub const START_TASK_1: Param = Param {
+    name: "start1",
+    kind: ParamKind::Name,
+    default: "you",
+    description: "device the paths of task 1 start at",
+};
+
+pub const START_TASK_2: Param = Param {
+    name: "start2",
+    kind: ParamKind::Name,
+    default: "svr",
+    description: "device the paths of task 2 start at",
+};
+
+pub const STOP_A: Param = Param {
+    name: "stop_a",
+    kind: ParamKind::Name,
+    default: "dac",
+    description: "first device the paths of task 2 have to visit",
+};
+
+pub const STOP_B: Param = Param {
+    name: "stop_b",
+    kind: ParamKind::Name,
+    default: "fft",
+    description: "second device the paths of task 2 have to visit",
+};
+
+pub const END: Param = Param {
+    name: "end",
+    kind: ParamKind::Name,
+    default: "out",
+    description: "device all paths end at",
+};
+
+pub struct Day11;
 
-    let start_1 = Instant::now();
-    let sol_task1 = find_paths_naive("you", "out", &input);
-    let end_1 = Instant::now();
-    println!(
-        "Task 1: {sol_task1}\tTook: {}µs",
-        (end_1 - start_1).as_micros()
-    );
-
-    let start_2 = Instant::now();
-    let sol_task2 = find_paths_with_stop("svr", "dac", "fft", "out", &input);
-    let end_2 = Instant::now();
-    println!(
-        "Task 2: {sol_task2}\tTook: {}µs",
-        (end_2 - start_2).as_micros()
-    );
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+
+    const PARAMS: &'static [Param] = &[START_TASK_1, START_TASK_2, STOP_A, STOP_B, END];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        let start: String = params.get(&START_TASK_1)?;
+        let end: String = params.get(&END)?;
+        Ok(find_paths_naive(&start, &end, input)? as Answer)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, SolveError> {
+        let start: String = params.get(&START_TASK_2)?;
+        let stop_a: String = params.get(&STOP_A)?;
+        let stop_b: String = params.get(&STOP_B)?;
+        let end: String = params.get(&END)?;
+        Ok(find_paths_with_stop(&start, &stop_a, &stop_b, &end, input)? as Answer)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
 }
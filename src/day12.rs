@@ -0,0 +1,404 @@
+use crate::{
+    generate::Rng,
+    params::Params,
+    parse::ParseError,
+    solution::{Answer, DEFAULT_VARIANT, Solution, SolveError, Variant},
+};
+
+/// A present, given by the cells it occupies as `(row, column)`.
+#[derive(Debug, Clone)]
+pub struct Shape {
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Shape {
+    /// All distinct rotations and reflections of the shape. The cells of every orientation are
+    /// shifted to the origin and sorted row-major.
+    fn orientations(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut orientations = Vec::new();
+        let mut cells = self
+            .cells
+            .iter()
+            .map(|&(row, column)| (row as isize, column as isize))
+            .collect::<Vec<_>>();
+
+        for flip in [false, true] {
+            if flip {
+                cells = cells.iter().map(|&(row, column)| (row, -column)).collect();
+            }
+            for _ in 0..4 {
+                cells = cells.iter().map(|&(row, column)| (column, -row)).collect();
+
+                let min_row = cells.iter().map(|cell| cell.0).min().unwrap_or_default();
+                let min_column = cells.iter().map(|cell| cell.1).min().unwrap_or_default();
+                let mut orientation = cells
+                    .iter()
+                    .map(|&(row, column)| {
+                        ((row - min_row) as usize, (column - min_column) as usize)
+                    })
+                    .collect::<Vec<_>>();
+                orientation.sort_unstable();
+
+                if !orientations.contains(&orientation) {
+                    orientations.push(orientation);
+                }
+            }
+        }
+
+        orientations
+    }
+
+    /// Length of the longer side of the bounding box.
+    fn extent(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&(row, column)| row.max(column) + 1)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// A region under a tree and the number of presents of each shape that have to fit into it.
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub width: usize,
+    pub height: usize,
+    pub counts: Vec<usize>,
+}
+
+pub struct Farm {
+    pub shapes: Vec<Shape>,
+    pub regions: Vec<Region>,
+}
+
+fn parse_region(line: &str, num_shapes: usize) -> Result<Region, ParseError> {
+    let Some((size_str, counts_str)) = line.split_once(":") else {
+        return Err(ParseError::at(line, line, "a region (WxH: counts)"));
+    };
+    let Some((width_str, height_str)) = size_str.split_once("x") else {
+        return Err(ParseError::at(line, size_str, "a region size (WxH)"));
+    };
+    let Ok(width) = width_str.parse() else {
+        return Err(ParseError::at(line, width_str, "a width"));
+    };
+    let Ok(height) = height_str.parse() else {
+        return Err(ParseError::at(line, height_str, "a height"));
+    };
+
+    let counts = counts_str
+        .split_ascii_whitespace()
+        .map(|count_str| {
+            count_str
+                .parse()
+                .map_err(|_| ParseError::at(line, count_str, "a number of presents"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if counts.len() != num_shapes {
+        return Err(ParseError::at(
+            line,
+            counts_str,
+            format!("a number of presents for each of the {num_shapes} shapes"),
+        ));
+    }
+
+    Ok(Region {
+        width,
+        height,
+        counts,
+    })
+}
+
+pub fn parse_input(input_data: &str) -> Result<Farm, ParseError> {
+    let mut shapes = Vec::new();
+    let mut regions = Vec::new();
+
+    let mut lines = input_data.lines().peekable();
+    while let Some(line) = lines.next() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some(index_str) = line.strip_suffix(":") else {
+            regions.push(
+                parse_region(line, shapes.len()).map_err(|err| err.within(input_data, line))?,
+            );
+            continue;
+        };
+
+        if !regions.is_empty() {
+            return Err(ParseError::at(input_data, line, "a region (WxH: counts)"));
+        }
+        if index_str.parse() != Ok(shapes.len()) {
+            return Err(ParseError::at(
+                input_data,
+                index_str,
+                format!("the shape index {}", shapes.len()),
+            ));
+        }
+
+        let mut cells = Vec::new();
+        let mut row = 0;
+        while let Some(shape_line) = lines.next_if(|line| !line.trim().is_empty()) {
+            for (column, c) in shape_line.char_indices() {
+                match c {
+                    '#' => cells.push((row, column)),
+                    '.' => {}
+                    _ => {
+                        return Err(ParseError::at(
+                            input_data,
+                            &shape_line[column..column + c.len_utf8()],
+                            "'#' or '.'",
+                        ));
+                    }
+                }
+            }
+            row += 1;
+        }
+        shapes.push(Shape { cells });
+    }
+
+    Ok(Farm { shapes, regions })
+}
+
+/// Six random 3x3 shapes and `size` random regions. About half of the regions are large enough to
+/// place every present into its own 3x3 square, the others are smaller than the area of their
+/// presents, so that no region needs a long search.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    const NUM_SHAPES: usize = 6;
+
+    let mut lines = Vec::new();
+    for shape_idx in 0..NUM_SHAPES {
+        lines.push(format!("{shape_idx}:"));
+        for row in 0..3 {
+            // the middle row and column are always filled, so the shape spans 3x3 cells
+            let line = (0..3)
+                .map(|column| {
+                    if row == 1 || column == 1 || rng.chance(0.5) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            lines.push(line);
+        }
+        lines.push(String::new());
+    }
+    let shape_areas = lines
+        .chunks(5)
+        .map(|shape| {
+            shape[1..4]
+                .iter()
+                .map(|line| line.matches('#').count())
+                .sum::<usize>()
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..size {
+        let width = rng.range(12..=50) as usize;
+        let height = rng.range(12..=50) as usize;
+
+        let mut counts = [0; NUM_SHAPES];
+        if rng.chance(0.5) {
+            for _ in 0..rng.range(1..=((width / 3) * (height / 3)) as u64) {
+                counts[rng.index(NUM_SHAPES)] += 1;
+            }
+        } else {
+            let mut area = 0;
+            while area <= width * height {
+                let shape_idx = rng.index(NUM_SHAPES);
+                counts[shape_idx] += 1;
+                area += shape_areas[shape_idx];
+            }
+        }
+
+        let counts = counts.map(|count| count.to_string());
+        lines.push(format!("{width}x{height}: {}", counts.join(" ")));
+    }
+
+    lines.join("\n")
+}
+
+/// Tries to cover the `width` x `height` grid from `pos` on with the remaining presents. Every
+/// free cell is either covered by a present placed with its first cell there or left empty, which
+/// is allowed for `slack` more cells.
+fn place_presents(
+    width: usize,
+    height: usize,
+    orientations: &[Vec<Vec<(usize, usize)>>],
+    counts: &mut [usize],
+    grid: &mut [bool],
+    mut pos: usize,
+    slack: usize,
+) -> bool {
+    if counts.iter().all(|&count| count == 0) {
+        return true;
+    }
+    while pos < grid.len() && grid[pos] {
+        pos += 1;
+    }
+    if pos == grid.len() {
+        return false;
+    }
+
+    let y = pos / width;
+    let x = pos % width;
+
+    for shape_idx in 0..counts.len() {
+        if counts[shape_idx] == 0 {
+            continue;
+        }
+
+        for orientation in &orientations[shape_idx] {
+            let (first_row, first_column) = orientation[0];
+            debug_assert_eq!(first_row, 0);
+            let Some(origin_x) = x.checked_sub(first_column) else {
+                continue;
+            };
+
+            let fits = orientation.iter().all(|&(row, column)| {
+                y + row < height
+                    && origin_x + column < width
+                    && !grid[(y + row) * width + origin_x + column]
+            });
+            if !fits {
+                continue;
+            }
+
+            let set_cells = |grid: &mut [bool], value: bool| {
+                for &(row, column) in orientation {
+                    grid[(y + row) * width + origin_x + column] = value;
+                }
+            };
+
+            set_cells(grid, true);
+            counts[shape_idx] -= 1;
+            let placed = place_presents(width, height, orientations, counts, grid, pos + 1, slack);
+            counts[shape_idx] += 1;
+            set_cells(grid, false);
+
+            if placed {
+                return true;
+            }
+        }
+    }
+
+    if slack > 0 {
+        grid[pos] = true;
+        let placed = place_presents(
+            width,
+            height,
+            orientations,
+            counts,
+            grid,
+            pos + 1,
+            slack - 1,
+        );
+        grid[pos] = false;
+        return placed;
+    }
+
+    false
+}
+
+/// Largest region whose presents are packed cell by cell, larger regions are only decided if the
+/// presents obviously fit or not.
+pub const MAX_PACKED_AREA: usize = 1_000_000;
+
+/// Whether the presents fit into the region. Fails if the region or its presents are too large to
+/// be counted or the presents have to be packed into a region larger than [`MAX_PACKED_AREA`].
+pub fn presents_fit(shapes: &[Shape], region: &Region) -> Result<bool, SolveError> {
+    let too_large = || {
+        SolveError::new(format!(
+            "the presents of the {}x{} region are too large to be counted",
+            region.width, region.height
+        ))
+    };
+    let area = region
+        .width
+        .checked_mul(region.height)
+        .ok_or_else(too_large)?;
+    let num_presents = region
+        .counts
+        .iter()
+        .try_fold(0usize, |sum, &count| sum.checked_add(count))
+        .ok_or_else(too_large)?;
+    let present_area = std::iter::zip(shapes, &region.counts)
+        .try_fold(0usize, |sum, (shape, &count)| {
+            sum.checked_add(shape.cells.len().checked_mul(count)?)
+        })
+        .ok_or_else(too_large)?;
+
+    if present_area > area {
+        return Ok(false);
+    }
+
+    // every present fits into its own square, no need to pack them tightly
+    let extent = shapes.iter().map(Shape::extent).max().unwrap_or_default();
+    if extent == 0 || (region.width / extent) * (region.height / extent) >= num_presents {
+        return Ok(true);
+    }
+
+    if area > MAX_PACKED_AREA {
+        return Err(SolveError::new(format!(
+            "the presents of the {}x{} region would have to be packed into more than {MAX_PACKED_AREA} cells",
+            region.width, region.height
+        )));
+    }
+
+    let orientations = shapes.iter().map(Shape::orientations).collect::<Vec<_>>();
+    // shapes without cells always fit
+    let mut counts = std::iter::zip(shapes, &region.counts)
+        .map(|(shape, &count)| if shape.cells.is_empty() { 0 } else { count })
+        .collect::<Vec<_>>();
+    // fill the grid along its shorter side, which keeps the frontier of undecided cells short and
+    // prunes dead ends much earlier; the region may be transposed, as all rotations are tried
+    let width = region.width.min(region.height);
+    let height = region.width.max(region.height);
+    let mut grid = vec![false; area];
+    Ok(place_presents(
+        width,
+        height,
+        &orientations,
+        &mut counts,
+        &mut grid,
+        0,
+        area - present_area,
+    ))
+}
+
+pub fn count_fitting_regions(farm: &Farm) -> Result<usize, SolveError> {
+    let mut count = 0;
+    for region in &farm.regions {
+        count += presents_fit(&farm.shapes, region)? as usize;
+    }
+    Ok(count)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Farm;
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input_data)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Ok(count_fitting_regions(input)? as Answer)
+    }
+
+    fn part2(_input: &Self::Input, _params: &Params) -> Result<Answer, SolveError> {
+        Err(SolveError::new("the last day has no second part"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_input(rng, size)
+    }
+
+    fn variants() -> Vec<Variant<Self::Input>> {
+        // there is no second part to solve on the last day
+        vec![Variant::new(1, DEFAULT_VARIANT, Self::part1)]
+    }
+}
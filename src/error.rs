@@ -0,0 +1,67 @@
+use std::{fmt::Display, io};
+
+use crate::{parse::ParseError, solution::SolveError};
+
+/// Error of the runner. Every kind of error is mapped to its own exit code, so that scripts can
+/// tell them apart.
+#[derive(Debug)]
+pub enum RunError {
+    /// The command line arguments are invalid.
+    Args(String),
+    /// A file could not be read or written.
+    Io { path: String, err: io::Error },
+    /// The input of a day could not be parsed.
+    Parse { day: u8, err: ParseError },
+    /// A variant of a part could not be solved.
+    Solve {
+        day: u8,
+        part: u8,
+        variant: &'static str,
+        err: SolveError,
+    },
+}
+
+impl RunError {
+    /// Exit code if the answers are wrong, e.g. a failed check, diverging variants or a benchmark
+    /// regression.
+    pub const FAILURE_EXIT_CODE: u8 = 1;
+
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Args(_) => 2,
+            Self::Io { .. } => 3,
+            Self::Parse { .. } => 4,
+            Self::Solve { .. } => 5,
+        }
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Args(message) => write!(f, "{message}"),
+            Self::Io { path, err } => write!(f, "Failed to access file (\"{path}\") due to {err}"),
+            Self::Parse { day, err } => write!(f, "Could not parse input of day {day}: {err}"),
+            Self::Solve {
+                day,
+                part,
+                variant,
+                err,
+            } => write!(
+                f,
+                "Could not solve day {day} task {part} (variant \"{variant}\"): {err}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Args(_) => None,
+            Self::Io { err, .. } => Some(err),
+            Self::Parse { err, .. } => Some(err),
+            Self::Solve { err, .. } => Some(err),
+        }
+    }
+}
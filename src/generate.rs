@@ -0,0 +1,64 @@
+use std::ops::RangeInclusive;
+
+/// Small random number generator (SplitMix64) for the generated inputs, so that the same seed
+/// always gives the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`. The modulo bias is negligible for the small ranges of the inputs.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a slice of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "no index into an empty slice");
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // the upper 53 bits fill the mantissa of a double in [0, 1)
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+/// Options of the `generate` subcommand.
+#[derive(Debug, Clone, Copy)]
+pub struct GenerateOptions {
+    /// Scales the generated input, e.g. the number of lines.
+    pub size: usize,
+    pub seed: u64,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self { size: 10, seed: 0 }
+    }
+}
@@ -0,0 +1,40 @@
+use solution::DynSolution;
+
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod error;
+pub mod generate;
+pub mod params;
+pub mod parse;
+pub mod pool;
+pub mod report;
+pub mod solution;
+pub mod watch;
+
+/// All solved days, ordered by day.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+];
@@ -1,74 +1,785 @@
-use day01::solve_day01;
-use day02::solve_day02;
-use day03::solve_day03;
-use day04::solve_day04;
-use day05::solve_day05;
-use day06::solve_day06;
-use day07::solve_day07;
-use day08::solve_day08;
-use day09::solve_day09;
-use day10::solve_day10;
-use day11::solve_day11;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-
-fn main() {
+use std::{
+    io::{self, ErrorKind},
+    ops::RangeInclusive,
+    path::Path,
+    process::ExitCode,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use sol::{
+    SOLUTIONS,
+    answers::{CheckResult, ExpectedAnswers},
+    bench::{Baseline, BenchOptions, print_benches},
+    day01::{self, Dial, Rotation, starts_with_counts},
+    error::RunError,
+    generate::GenerateOptions,
+    params::{ParamKind, Params},
+    pool::Pool,
+    report::{Format, print_reports, print_start_counts, print_trace},
+    solution::{DayReport, DynSolution, VariantFilter},
+    watch::{FileStamp, answer_changes, print_answer_changes},
+};
+
+/// The days the user asked for on the command line.
+enum DaySelection {
+    Single(u8),
+    Range(RangeInclusive<u8>),
+}
+
+impl DaySelection {
+    fn parse(days_str: &str) -> Option<Self> {
+        if days_str == "all" {
+            let first = SOLUTIONS.first()?.day();
+            let last = SOLUTIONS.last()?.day();
+            return Some(Self::Range(first..=last));
+        }
+
+        match days_str.split_once("-") {
+            Some((first_str, last_str)) => {
+                let first = first_str.parse().ok()?;
+                let last = last_str.parse().ok()?;
+                (first <= last).then_some(Self::Range(first..=last))
+            }
+            None => days_str.parse().ok().map(Self::Single),
+        }
+    }
+
+    /// The registered solutions of the selected days. It is an error if a single day without a
+    /// solution or a range without any solved day is selected.
+    fn solutions(&self) -> Result<Vec<&'static dyn DynSolution>, RunError> {
+        let days = match self {
+            Self::Single(day) => *day..=*day,
+            Self::Range(days) => days.clone(),
+        };
+        let solutions = SOLUTIONS
+            .iter()
+            .copied()
+            .filter(|solution| days.contains(&solution.day()))
+            .collect::<Vec<_>>();
+
+        if solutions.is_empty() {
+            let available = SOLUTIONS
+                .iter()
+                .map(|solution| solution.day().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let selected = match self {
+                Self::Single(day) => format!("Day {day} is"),
+                Self::Range(days) => format!("Days {}-{} are", days.start(), days.end()),
+            };
+            return Err(RunError::Args(format!(
+                "{selected} not solved, available days are: {available}"
+            )));
+        }
+        Ok(solutions)
+    }
+}
+
+/// A solved day together with its input.
+type DayInput = (&'static dyn DynSolution, String);
+
+/// Where the puzzle input is read from.
+enum InputSource<'a> {
+    /// `-`, the input is read from stdin.
+    Stdin,
+    /// An absolute or relative path, which is used as given.
+    Path(&'a str),
+    /// A bare file name, which is looked up in the directory of every day (`dayNN/<name>`).
+    DayFile(&'a str),
+}
+
+impl<'a> InputSource<'a> {
+    fn parse(input_file: &'a str) -> Self {
+        let path = Path::new(input_file);
+        if input_file == "-" {
+            Self::Stdin
+        } else if path.is_absolute() || path.components().count() > 1 {
+            Self::Path(input_file)
+        } else {
+            Self::DayFile(input_file)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(exit_code) => ExitCode::from(exit_code),
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+/// Runs the command given on the command line and returns the exit code. Errors of single days
+/// are reported right away and the remaining days are still run, then the highest exit code of
+/// all errors is returned.
+fn run() -> Result<u8, RunError> {
     let mut args = std::env::args();
-    if args.len() != 3 {
-        eprintln!("Usage: {} <day> input.txt", args.next().unwrap());
-        return;
-    }
-    let mut args = args.skip(1);
-    let day_str = args.next().unwrap();
-    let day: u64 = match day_str.parse() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Cannot parse day \"{day_str}\"!");
-            return;
+    let program = args.next().unwrap_or_default();
+
+    let mut format = Format::Text;
+    let mut check = false;
+    let mut bench_options = BenchOptions::default();
+    let mut baseline_path = String::from(Baseline::DEFAULT_PATH);
+    let mut save_baseline = false;
+    let mut threshold = 0.2;
+    let mut filter = VariantFilter::default();
+    let mut params = Params::default();
+    let mut generate_options = GenerateOptions::default();
+    let mut seed = None;
+    let mut interval = Duration::from_millis(500);
+    let mut landings = None;
+    let mut passes = None;
+    let mut jobs = 1;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let format_str = args.next().unwrap_or_default();
+                let Ok(format_) = Format::try_from(format_str.as_str()) else {
+                    return Err(RunError::Args(format!(
+                        "Unknown format \"{format_str}\", expected json, csv or text!"
+                    )));
+                };
+                format = format_;
+            }
+            "--check" => check = true,
+            "--part" => {
+                let part_str = args.next().unwrap_or_default();
+                let part = match part_str.as_str() {
+                    "1" => 1,
+                    "2" => 2,
+                    _ => {
+                        return Err(RunError::Args(format!(
+                            "Invalid part \"{part_str}\", expected 1 or 2!"
+                        )));
+                    }
+                };
+                filter.part = Some(part);
+            }
+            "--param" => {
+                let param_str = args.next().unwrap_or_default();
+                let Some((name, value)) = param_str.split_once("=") else {
+                    return Err(RunError::Args(format!(
+                        "Cannot parse parameter \"{param_str}\", expected name=value!"
+                    )));
+                };
+                params.set(name, value);
+            }
+            "--variant" => {
+                let Some(variant) = args.next() else {
+                    return Err(RunError::Args(String::from("Missing name of --variant!")));
+                };
+                filter.variant = Some(variant);
+            }
+            "--warmup" | "--iterations" => {
+                let count_str = args.next().unwrap_or_default();
+                let Ok(count) = count_str.parse() else {
+                    return Err(RunError::Args(format!(
+                        "Cannot parse {arg} \"{count_str}\"!"
+                    )));
+                };
+                if arg == "--warmup" {
+                    bench_options.warmup = count;
+                } else {
+                    bench_options.iterations = count;
+                }
+            }
+            "--size" => {
+                let size_str = args.next().unwrap_or_default();
+                match size_str.parse() {
+                    Ok(size) if size > 0 => generate_options.size = size,
+                    _ => {
+                        return Err(RunError::Args(format!(
+                            "Invalid size \"{size_str}\", expected a positive number!"
+                        )));
+                    }
+                }
+            }
+            "--seed" => {
+                let seed_str = args.next().unwrap_or_default();
+                let Ok(seed_) = seed_str.parse() else {
+                    return Err(RunError::Args(format!("Cannot parse seed \"{seed_str}\"!")));
+                };
+                seed = Some(seed_);
+            }
+            "--jobs" => {
+                let jobs_str = args.next().unwrap_or_default();
+                match jobs_str.parse() {
+                    Ok(jobs_) if jobs_ > 0 => jobs = jobs_,
+                    _ => {
+                        return Err(RunError::Args(format!(
+                            "Invalid number of jobs \"{jobs_str}\", expected a positive number!"
+                        )));
+                    }
+                }
+            }
+            "--landings" | "--passes" => {
+                let count_str = args.next().unwrap_or_default();
+                let Ok(count) = count_str.parse() else {
+                    return Err(RunError::Args(format!(
+                        "Cannot parse {arg} \"{count_str}\"!"
+                    )));
+                };
+                if arg == "--landings" {
+                    landings = Some(count);
+                } else {
+                    passes = Some(count);
+                }
+            }
+            "--interval" => {
+                let interval_str = args.next().unwrap_or_default();
+                let Ok(millis) = interval_str.parse() else {
+                    return Err(RunError::Args(format!(
+                        "Cannot parse interval \"{interval_str}\"!"
+                    )));
+                };
+                interval = Duration::from_millis(millis);
+            }
+            "--baseline" => baseline_path = args.next().unwrap_or_default(),
+            "--save-baseline" => save_baseline = true,
+            "--threshold" => {
+                let threshold_str = args.next().unwrap_or_default();
+                let Ok(percent) = threshold_str.parse::<f64>() else {
+                    return Err(RunError::Args(format!(
+                        "Cannot parse threshold \"{threshold_str}\"!"
+                    )));
+                };
+                threshold = percent / 100.0;
+            }
+            _ => positional.push(arg),
         }
+    }
+
+    let usage = format!(
+        "Usage: {program} [--format json|csv|text] [--check] [--jobs N] [--part 1|2] [--variant name] [--param name=value] <day|first-last|all> <file|path|->\n       {program} bench [--warmup N] [--iterations N] [--baseline file.csv] [--save-baseline] [--threshold percent] [--part 1|2] [--variant name] [--param name=value] <day|first-last|all> <file|path|->\n       {program} watch [--interval ms] [--jobs N] [--part 1|2] [--variant name] [--param name=value] <day|first-last|all> <file|path>\n       {program} generate [--size N] [--seed N] <day>\n       {program} trace [--format json|csv|text] [--param name=value] <file|path|->\n       {program} starts [--landings N] [--passes N] [--format json|csv|text] [--param name=value] <file|path|->"
+    );
+
+    if positional.first().is_some_and(|arg| arg == "generate") {
+        let [_, day_str] = positional.as_slice() else {
+            return Err(RunError::Args(usage));
+        };
+        return generate(day_str, generate_options, seed);
+    }
+
+    if positional.first().is_some_and(|arg| arg == "trace") {
+        let [_, input_file] = positional.as_slice() else {
+            return Err(RunError::Args(usage));
+        };
+        return inspect_dial(input_file, &params, |dial, rotations| {
+            let trace = dial.trace(rotations).map_err(|err| RunError::Solve {
+                day: 1,
+                part: 2,
+                variant: "trace",
+                err,
+            })?;
+            print_trace(&trace, format);
+            Ok(())
+        });
+    }
+
+    if positional.first().is_some_and(|arg| arg == "starts") {
+        let [_, input_file] = positional.as_slice() else {
+            return Err(RunError::Args(usage));
+        };
+        return inspect_dial(input_file, &params, |dial, rotations| {
+            let counts = dial
+                .counts_by_start(rotations)
+                .map_err(|err| RunError::Solve {
+                    day: 1,
+                    part: 2,
+                    variant: "starts",
+                    err,
+                })?;
+            print_start_counts(&starts_with_counts(&counts, landings, passes), format);
+            Ok(())
+        });
+    }
+
+    let bench = positional.first().is_some_and(|arg| arg == "bench");
+    let watch = positional.first().is_some_and(|arg| arg == "watch");
+    let [day_str, input_file] = &positional[(bench || watch) as usize..] else {
+        return Err(RunError::Args(usage));
+    };
+    let Some(selection) = DaySelection::parse(day_str) else {
+        return Err(RunError::Args(format!("Cannot parse day \"{day_str}\"!")));
     };
 
-    if day == 0 || day > 12 {
-        eprintln!("Invalid day: {day}!");
-        return;
+    let solutions = selection.solutions()?;
+    let pool = Pool::new(jobs);
+
+    if watch {
+        return watch_inputs(
+            &solutions, &selection, input_file, &filter, &params, interval, &pool,
+        );
     }
 
-    let input_file = args.next().unwrap();
-    let input_path = format!("day{day:02}/{input_file}");
+    let mut failure = None;
+    let inputs = load_inputs(&solutions, &selection, input_file, &mut failure)?;
+    let inputs = select_variants(inputs, &selection, &filter)?;
+    check_params(&inputs, &params)?;
 
-    let input_data = match std::fs::read_to_string(&input_path) {
-        Ok(str) => str,
-        Err(err) => {
+    if bench {
+        if jobs > 1 {
+            return Err(RunError::Args(String::from(
+                "Benchmarks always run on a single thread, --jobs cannot be used!",
+            )));
+        }
+        if bench_options.iterations == 0 {
+            return Err(RunError::Args(String::from(
+                "At least one iteration is needed!",
+            )));
+        }
+        let benches = inputs
+            .iter()
+            .filter_map(|(solution, input_data)| {
+                solution
+                    .bench(input_data, &bench_options, &filter, &params)
+                    .inspect_err(|err| report_error(err, input_data, &mut failure))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        let mut baseline = match Baseline::load(&baseline_path) {
+            Ok(Some(baseline)) => baseline,
+            Ok(None) => return Err(invalid_data(&baseline_path, "invalid baseline")),
+            Err(err) => {
+                return Err(RunError::Io {
+                    path: baseline_path,
+                    err,
+                });
+            }
+        };
+
+        let num_regressions = print_benches(&benches, &baseline, input_file, threshold);
+
+        if save_baseline {
+            baseline.update(input_file, &benches);
+            baseline.save(&baseline_path).map_err(|err| RunError::Io {
+                path: baseline_path,
+                err,
+            })?;
+        }
+
+        if num_regressions > 0 {
             eprintln!(
-                "Failed to read file (\"{input_path}\") due to {}",
-                err.to_string()
+                "{num_regressions} task(s) got more than {}% slower than the baseline!",
+                threshold * 100.0
             );
-            return;
+            fail(&mut failure, RunError::FAILURE_EXIT_CODE);
         }
+        return Ok(failure.unwrap_or(0));
+    }
+
+    let mut runs = Vec::new();
+    for (solution, input_data) in &inputs {
+        let mut day_filter = filter.clone();
+        // inputs can need other parameters than the puzzle, which are stored with their answers
+        let params = if check {
+            let expected = load_expected(solution.day())?;
+            // inputs may only contain what one of the parts needs, so only parts with answers are
+            // checked, unless the input has no answers at all
+            if let [part] = expected.parts(input_file)[..] {
+                day_filter.part.get_or_insert(part);
+            }
+            let mut input_params = expected.params(input_file);
+            input_params.extend(&params);
+            input_params
+        } else {
+            params.clone()
+        };
+        runs.push((*solution, input_data.as_str(), day_filter, params));
+    }
+
+    let mut reports = Vec::new();
+    let results = pool.map(&runs, |(solution, input_data, filter, params)| {
+        solution.run(input_data, filter, params, &pool)
+    });
+    for ((_, input_data, _, _), result) in runs.iter().zip(results) {
+        match result {
+            Ok(report) => {
+                for err in report.solve_errors() {
+                    report_error(&err, input_data, &mut failure);
+                }
+                reports.push(report);
+            }
+            Err(err) => report_error(&err, input_data, &mut failure),
+        }
+    }
+
+    let mut success = if check {
+        check_reports(&reports, input_file)?
+    } else {
+        let summary = matches!(selection, DaySelection::Range(_));
+        print_reports(&reports, format, summary);
+        true
     };
 
-    match day {
-        1 => solve_day01(input_data),
-        2 => solve_day02(input_data),
-        3 => solve_day03(input_data),
-        4 => solve_day04(input_data),
-        5 => solve_day05(input_data),
-        6 => solve_day06(input_data),
-        7 => solve_day07(input_data),
-        8 => solve_day08(input_data),
-        9 => solve_day09(input_data),
-        10 => solve_day10(input_data),
-        11 => solve_day11(input_data),
-        _ => todo!("Unsolved day"),
+    for report in &reports {
+        for divergence in report.divergences() {
+            eprintln!(
+                "Day {} Task {}: variant \"{}\" answered {} on \"{input_file}\", but \"{}\" answered {}!",
+                report.day,
+                divergence.diverged.part,
+                divergence.diverged.variant,
+                divergence.diverged.answer,
+                divergence.reference.variant,
+                divergence.reference.answer
+            );
+            success = false;
+        }
+    }
+
+    if !success {
+        fail(&mut failure, RunError::FAILURE_EXIT_CODE);
     }
+    Ok(failure.unwrap_or(0))
+}
+
+/// Runs the selected days whenever one of their input files changes and prints the answers that
+/// changed since the previous run. Only returns on invalid arguments, errors of single days are
+/// reported and the files are watched further.
+fn watch_inputs(
+    solutions: &[&'static dyn DynSolution],
+    selection: &DaySelection,
+    input_file: &str,
+    filter: &VariantFilter,
+    params: &Params,
+    interval: Duration,
+    pool: &Pool,
+) -> Result<u8, RunError> {
+    let paths = match InputSource::parse(input_file) {
+        InputSource::Stdin => {
+            return Err(RunError::Args(String::from(
+                "stdin cannot be watched, use a file instead!",
+            )));
+        }
+        InputSource::Path(path) => vec![path.to_string()],
+        InputSource::DayFile(file_name) => solutions
+            .iter()
+            .map(|solution| format!("day{:02}/{file_name}", solution.day()))
+            .collect(),
+    };
+    let summary = matches!(selection, DaySelection::Range(_));
+
+    let mut stamps = Vec::new();
+    let mut previous_reports: Option<Vec<DayReport>> = None;
+    loop {
+        let new_stamps = paths
+            .iter()
+            .map(|path| FileStamp::of(path))
+            .collect::<Vec<_>>();
+        if new_stamps == stamps {
+            thread::sleep(interval);
+            continue;
+        }
+        stamps = new_stamps;
+
+        // the exit codes of failed days do not matter, as the files are watched until killed
+        let mut failure = None;
+        let inputs = match load_inputs(solutions, selection, input_file, &mut failure) {
+            Ok(inputs) => select_variants(inputs, selection, filter)?,
+            Err(err @ RunError::Args(_)) => return Err(err),
+            Err(err) => {
+                eprintln!("{err}");
+                Vec::new()
+            }
+        };
+        check_params(&inputs, params)?;
+
+        let results = pool.map(&inputs, |(solution, input_data)| {
+            solution.run(input_data, filter, params, pool)
+        });
+        let reports = inputs
+            .iter()
+            .zip(results)
+            .filter_map(|((_, input_data), result)| match result {
+                Ok(report) => {
+                    for err in report.solve_errors() {
+                        report_error(&err, input_data, &mut failure);
+                    }
+                    Some(report)
+                }
+                Err(err) => {
+                    report_error(&err, input_data, &mut failure);
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        print_reports(&reports, Format::Text, summary);
+        if let Some(previous_reports) = &previous_reports {
+            println!();
+            print_answer_changes(&answer_changes(previous_reports, &reports));
+        }
+        previous_reports = Some(reports);
+
+        eprintln!("\nWatching {} for changes...", paths.join(", "));
+    }
+}
+
+/// Prints a random input of a single day. Without a seed, the input is random, but the seed is
+/// printed to reproduce it.
+fn generate(
+    day_str: &str,
+    mut options: GenerateOptions,
+    seed: Option<u64>,
+) -> Result<u8, RunError> {
+    let Ok(day) = day_str.parse() else {
+        return Err(RunError::Args(format!(
+            "Cannot parse day \"{day_str}\", inputs can only be generated for a single day!"
+        )));
+    };
+    let solutions = DaySelection::Single(day).solutions()?;
+
+    options.seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        eprintln!("Seed: {seed}");
+        seed
+    });
+    for solution in solutions {
+        println!("{}", solution.generate(&options));
+    }
+    Ok(0)
+}
+
+/// Parses the input of day 1 and passes it to `print` together with the dial of the puzzle, for
+/// the subcommands that inspect the dial. Errors of `print` are reported like errors of a day.
+fn inspect_dial(
+    input_file: &str,
+    params: &Params,
+    print: impl Fn(&Dial, &[Rotation]) -> Result<(), RunError>,
+) -> Result<u8, RunError> {
+    let selection = DaySelection::Single(1);
+    let solutions = selection.solutions()?;
+    let mut failure = None;
+    let inputs = load_inputs(&solutions, &selection, input_file, &mut failure)?;
+    check_params(&inputs, params)?;
+    let dial =
+        day01::dial(params).map_err(|err| RunError::Args(format!("Invalid dial: {err}!")))?;
+
+    for (_, input_data) in &inputs {
+        match day01::parse_input(input_data) {
+            Ok(rotations) => {
+                if let Err(err) = print(&dial, &rotations) {
+                    report_error(&err, input_data, &mut failure);
+                }
+            }
+            Err(err) => report_error(&RunError::Parse { day: 1, err }, input_data, &mut failure),
+        }
+    }
+    Ok(failure.unwrap_or(0))
+}
+
+/// Prints the error of a single day, including the offending line of the input for parse errors,
+/// and remembers its exit code.
+fn report_error(err: &RunError, input_data: &str, failure: &mut Option<u8>) {
+    eprintln!("{err}");
+    if let RunError::Parse { err, .. } = err {
+        eprintln!("{}", err.snippet(input_data));
+    }
+    fail(failure, err.exit_code());
+}
+
+/// Remembers a failure, keeping the highest exit code of all failures so far.
+fn fail(failure: &mut Option<u8>, exit_code: u8) {
+    *failure = Some(failure.map_or(exit_code, |code| code.max(exit_code)));
+}
+
+fn invalid_data(path: &str, message: &str) -> RunError {
+    RunError::Io {
+        path: path.to_string(),
+        err: io::Error::new(ErrorKind::InvalidData, message),
+    }
+}
+
+/// Reads the input of the solutions of all selected days. Stdin and explicit paths can only be
+/// used for a single day, a bare file name is looked up in the directory of every day. If a range
+/// of days is selected, days without such an input file are skipped. Files that cannot be read are
+/// reported as failures.
+fn load_inputs(
+    solutions: &[&'static dyn DynSolution],
+    selection: &DaySelection,
+    input_file: &str,
+    failure: &mut Option<u8>,
+) -> Result<Vec<DayInput>, RunError> {
+    let file_name = match InputSource::parse(input_file) {
+        InputSource::DayFile(file_name) => file_name,
+        InputSource::Stdin | InputSource::Path(_)
+            if matches!(selection, DaySelection::Range(_)) =>
+        {
+            return Err(RunError::Args(format!(
+                "\"{input_file}\" can only be used for a single day!"
+            )));
+        }
+        InputSource::Stdin => {
+            let input_data = io::read_to_string(io::stdin()).map_err(|err| RunError::Io {
+                path: String::from("<stdin>"),
+                err,
+            })?;
+            return Ok(solutions
+                .iter()
+                .map(|&solution| (solution, input_data.clone()))
+                .collect());
+        }
+        InputSource::Path(path) => {
+            let input_data = std::fs::read_to_string(path).map_err(|err| RunError::Io {
+                path: path.to_string(),
+                err,
+            })?;
+            return Ok(solutions
+                .iter()
+                .map(|&solution| (solution, input_data.clone()))
+                .collect());
+        }
+    };
+
+    let mut inputs = Vec::new();
+    for &solution in solutions {
+        let day = solution.day();
+        let input_path = format!("day{day:02}/{file_name}");
+
+        match std::fs::read_to_string(&input_path) {
+            Ok(input_data) => inputs.push((solution, input_data)),
+            Err(err)
+                if err.kind() == ErrorKind::NotFound
+                    && matches!(selection, DaySelection::Range(_)) =>
+            {
+                eprintln!("Skipping day {day}, \"{input_path}\" does not exist.");
+            }
+            Err(err) => {
+                let err = RunError::Io {
+                    path: input_path,
+                    err,
+                };
+                report_error(&err, "", failure);
+            }
+        }
+    }
+
+    Ok(inputs)
+}
+
+/// Drops the days without a variant selected by `filter`. If a single day is selected, this is an
+/// error that lists the variants of the day instead.
+fn select_variants(
+    inputs: Vec<DayInput>,
+    selection: &DaySelection,
+    filter: &VariantFilter,
+) -> Result<Vec<DayInput>, RunError> {
+    let mut selected = Vec::new();
+    for (solution, input_data) in inputs {
+        let variant_names = solution.variant_names();
+        if variant_names
+            .iter()
+            .any(|&(part, variant)| filter.matches(part, variant))
+        {
+            selected.push((solution, input_data));
+            continue;
+        }
+
+        let available = variant_names
+            .iter()
+            .map(|(part, variant)| format!("{part} ({variant})"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match selection {
+            DaySelection::Single(day) => {
+                return Err(RunError::Args(format!(
+                    "Day {day} has no such part or variant, available are: {available}"
+                )));
+            }
+            DaySelection::Range(_) => eprintln!(
+                "Skipping day {}, it has no such part or variant.",
+                solution.day()
+            ),
+        }
+    }
+    Ok(selected)
+}
+
+/// Checks that every set parameter is declared by one of the days and that its value is valid.
+fn check_params(inputs: &[DayInput], params: &Params) -> Result<(), RunError> {
+    let declared = inputs
+        .iter()
+        .flat_map(|(solution, _)| {
+            solution
+                .params()
+                .iter()
+                .map(|param| (solution.day(), param))
+        })
+        .collect::<Vec<_>>();
+
+    for (name, value) in params.iter() {
+        let mut is_declared = false;
+        for (day, param) in declared.iter().filter(|(_, param)| param.name == name) {
+            is_declared = true;
+            if !param.accepts(value) {
+                let expected = match param.kind {
+                    ParamKind::Number => "a non-negative number",
+                    ParamKind::Name => "a non-empty name",
+                    ParamKind::Numbers => "a comma separated list of non-negative numbers",
+                };
+                return Err(RunError::Args(format!(
+                    "Invalid value \"{value}\" of parameter {name} of day {day}, expected {expected}!"
+                )));
+            }
+        }
+
+        if !is_declared {
+            let available = declared
+                .iter()
+                .map(|(day, param)| format!("{} (day {day})", param.name))
+                .collect::<Vec<_>>();
+            return Err(RunError::Args(if available.is_empty() {
+                format!("Unknown parameter \"{name}\", the selected days have no parameters!")
+            } else {
+                format!(
+                    "Unknown parameter \"{name}\", available are: {}",
+                    available.join(", ")
+                )
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+fn load_expected(day: u8) -> Result<ExpectedAnswers, RunError> {
+    let path = ExpectedAnswers::path(day);
+    match ExpectedAnswers::load(day) {
+        Ok(Some(expected)) => Ok(expected),
+        Ok(None) => Err(invalid_data(&path, "invalid expected answers")),
+        Err(err) => Err(RunError::Io { path, err }),
+    }
+}
+
+/// Compares all answers against the expected answers of their day and prints the results.
+/// Returns `false` if any answer did not match.
+fn check_reports(reports: &[DayReport], input_file: &str) -> Result<bool, RunError> {
+    let mut all_passed = true;
+
+    for report in reports {
+        let expected = load_expected(report.day)?;
+
+        for part in &report.parts {
+            let result = match expected.check(input_file, part) {
+                CheckResult::Pass => format!("PASS\t{}", part.answer),
+                CheckResult::Fail { expected } => {
+                    all_passed = false;
+                    format!("FAIL\t{} (expected {expected})", part.answer)
+                }
+                CheckResult::Unknown => format!("????\t{} (no expected answer)", part.answer),
+            };
+            println!(
+                "Day {:>2} Task {} ({}):\t{result}",
+                report.day, part.part, part.variant
+            );
+        }
+    }
+
+    Ok(all_passed)
 }
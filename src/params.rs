@@ -0,0 +1,90 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::solution::SolveError;
+
+/// A constant of a puzzle that a day exposes, so that it can be changed with `--param name=value`
+/// on the command line, e.g. because the example uses another value than the real input.
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    /// Value of the puzzle, used if the parameter is not set.
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    /// A non-negative integer.
+    Number,
+    /// An arbitrary string, e.g. the name of a node.
+    Name,
+    /// A comma separated list of non-negative integers.
+    Numbers,
+}
+
+impl Param {
+    /// Whether `value` is a valid value of this parameter.
+    pub fn accepts(&self, value: &str) -> bool {
+        match self.kind {
+            ParamKind::Number => value.parse::<u64>().is_ok(),
+            ParamKind::Name => !value.is_empty(),
+            ParamKind::Numbers => value.split(',').all(|number| number.parse::<u64>().is_ok()),
+        }
+    }
+}
+
+/// The values of the parameters set on the command line. Parameters that are not set have their
+/// default value.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Sets all parameters that are set in `other`, overriding the values of `self`.
+    pub fn extend(&mut self, other: &Params) {
+        for (name, value) in other.iter() {
+            self.set(name, value);
+        }
+    }
+
+    /// The names and values of all parameters that are set.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// The value of `param`, or its default value if it is not set.
+    pub fn get<T: FromStr>(&self, param: &Param) -> Result<T, SolveError> {
+        let value = self.value(param);
+        value.parse().map_err(|_| invalid_value(param, value))
+    }
+
+    /// The comma separated values of `param`, or its default values if it is not set.
+    pub fn get_list<T: FromStr>(&self, param: &Param) -> Result<Vec<T>, SolveError> {
+        let value = self.value(param);
+        value
+            .split(',')
+            .map(|item| item.parse().map_err(|_| invalid_value(param, value)))
+            .collect()
+    }
+
+    fn value(&self, param: &Param) -> &str {
+        self.values
+            .get(param.name)
+            .map_or(param.default, String::as_str)
+    }
+}
+
+fn invalid_value(param: &Param, value: &str) -> SolveError {
+    SolveError::new(format!(
+        "invalid value \"{value}\" of parameter {}",
+        param.name
+    ))
+}
@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+/// Error of a puzzle input parser, pointing at the offending text in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending text.
+    pub column: usize,
+    /// The offending text, empty if the input ended unexpectedly.
+    pub found: String,
+    /// Description of what was expected instead.
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        found: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            found: found.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error pointing at `found`, which has to be a subslice of `input_data`.
+    pub fn at(input_data: &str, found: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(input_data, found);
+        Self::new(line, column, found, expected)
+    }
+
+    /// Converts an error, that was created while parsing `part`, into an error relative to
+    /// `input_data`. `part` has to be a subslice of `input_data`.
+    pub fn within(self, input_data: &str, part: &str) -> Self {
+        let (part_line, part_column) = position(input_data, part);
+        let column = if self.line == 1 {
+            part_column + self.column - 1
+        } else {
+            self.column
+        };
+        Self {
+            line: part_line + self.line - 1,
+            column,
+            ..self
+        }
+    }
+
+    /// Renders the offending line of `input_data` with a caret below the offending text.
+    pub fn snippet(&self, input_data: &str) -> String {
+        let line = input_data.lines().nth(self.line - 1).unwrap_or_default();
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+        let marker = "^".repeat(self.found.chars().count().max(1));
+        format!(
+            "{padding} |\n{line_number} | {line}\n{padding} | {}{marker} expected {}",
+            " ".repeat(self.column - 1),
+            self.expected
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.found.is_empty() {
+            write!(
+                f,
+                "line {}, column {}: expected {}, found nothing",
+                self.line, self.column, self.expected
+            )
+        } else {
+            write!(
+                f,
+                "line {}, column {}: expected {}, found \"{}\"",
+                self.line, self.column, self.expected, self.found
+            )
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based line and column of the subslice `part` in `input_data`.
+fn position(input_data: &str, part: &str) -> (usize, usize) {
+    let offset = (part.as_ptr() as usize).wrapping_sub(input_data.as_ptr() as usize);
+    debug_assert!(offset <= input_data.len(), "not a subslice of the input");
+
+    let before = input_data.get(..offset).unwrap_or(input_data);
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
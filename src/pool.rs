@@ -0,0 +1,78 @@
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::{self, ScopedJoinHandle},
+};
+
+/// Runs work on up to `jobs` threads at the same time, including the calling thread.
+///
+/// Nested calls of [`Pool::map`] share the same limit. An item is run on the calling thread if no
+/// other thread is free, so waiting for a free thread never blocks and the pool cannot deadlock.
+#[derive(Debug)]
+pub struct Pool {
+    free_threads: AtomicUsize,
+}
+
+enum Slot<'scope, R> {
+    Done(R),
+    Running(ScopedJoinHandle<'scope, R>),
+}
+
+impl Pool {
+    /// A pool with `jobs` threads, a single job runs everything on the calling thread.
+    pub fn new(jobs: usize) -> Self {
+        Self {
+            free_threads: AtomicUsize::new(jobs.saturating_sub(1)),
+        }
+    }
+
+    fn try_acquire(&self) -> bool {
+        self.free_threads
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |free| {
+                free.checked_sub(1)
+            })
+            .is_ok()
+    }
+
+    fn release(&self) {
+        self.free_threads.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Applies `f` to all items and returns the results in the order of the items.
+    pub fn map<T: Sync, R: Send>(&self, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+        let f = &f;
+        thread::scope(|scope| {
+            let slots = items
+                .iter()
+                .map(|item| {
+                    if self.try_acquire() {
+                        Slot::Running(scope.spawn(move || {
+                            let result = f(item);
+                            self.release();
+                            result
+                        }))
+                    } else {
+                        Slot::Done(f(item))
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            slots
+                .into_iter()
+                .map(|slot| match slot {
+                    Slot::Done(result) => result,
+                    Slot::Running(handle) => handle
+                        .join()
+                        .unwrap_or_else(|payload| panic::resume_unwind(payload)),
+                })
+                .collect()
+        })
+    }
+}
+
+impl Default for Pool {
+    /// A pool that runs everything on the calling thread.
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
@@ -0,0 +1,265 @@
+use std::time::Duration;
+
+use crate::{
+    day01::{StartCounts, TraceStep},
+    solution::{DEFAULT_VARIANT, DayReport},
+};
+
+/// Output format of the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable output, a table if several days are run.
+    Text,
+    /// One JSON array containing a record per solved part.
+    Json,
+    /// One CSV line with a header per solved part.
+    Csv,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Prints the reports in the given format. `summary` selects the table instead of the plain
+/// listing for the text format.
+pub fn print_reports(reports: &[DayReport], format: Format, summary: bool) {
+    match format {
+        Format::Text if summary => print_summary(reports),
+        Format::Text => reports.iter().for_each(print_day),
+        Format::Json => print_json(reports),
+        Format::Csv => print_csv(reports),
+    }
+}
+
+pub fn print_day(report: &DayReport) {
+    println!("Parse:\t\tTook: {}µs", report.parse_duration.as_micros());
+    for part in &report.parts {
+        let name = if part.variant == DEFAULT_VARIANT {
+            format!("Task {}", part.part)
+        } else {
+            format!("Task {} ({})", part.part, part.variant)
+        };
+        println!(
+            "{name}:\t{}\tTook: {}µs",
+            part.answer,
+            part.duration.as_micros()
+        );
+    }
+}
+
+pub fn print_summary(reports: &[DayReport]) {
+    let rows = reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(|part| {
+                [
+                    report.day.to_string(),
+                    part.part.to_string(),
+                    part.variant.to_string(),
+                    part.answer.to_string(),
+                    format!("{}µs", part.duration.as_micros()),
+                ]
+            })
+        })
+        .collect::<Vec<_>>();
+    print_table(["Day", "Task", "Variant", "Answer", "Took"], &rows);
+
+    let total: Duration = reports
+        .iter()
+        .map(|report| {
+            report.parse_duration
+                + report
+                    .parts
+                    .iter()
+                    .map(|part| part.duration)
+                    .sum::<Duration>()
+        })
+        .sum();
+    println!("\nTotal: {}µs", total.as_micros());
+}
+
+/// Prints the rows as a table with right-aligned columns.
+pub fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|cell| cell.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &mut dyn Iterator<Item = &str>| {
+        let cells = row
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .collect::<Vec<_>>();
+        println!("{}", cells.join(" | "));
+    };
+
+    print_row(&mut header.into_iter());
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in rows {
+        print_row(&mut row.iter().map(String::as_str));
+    }
+}
+
+pub fn print_json(reports: &[DayReport]) {
+    let records = reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(|part| {
+                format!(
+                    "  {{\"day\": {}, \"part\": {}, \"variant\": \"{}\", \"answer\": {}, \"duration_ns\": {}}}",
+                    report.day,
+                    part.part,
+                    part.variant,
+                    part.answer,
+                    part.duration.as_nanos()
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    print_json_array(&records);
+}
+
+/// Prints the records, which are already formatted as JSON objects, as a JSON array.
+fn print_json_array(records: &[String]) {
+    if records.is_empty() {
+        println!("[]");
+    } else {
+        println!("[\n{}\n]", records.join(",\n"));
+    }
+}
+
+pub fn print_csv(reports: &[DayReport]) {
+    println!("day,part,variant,answer,duration_ns");
+    for report in reports {
+        for part in &report.parts {
+            println!(
+                "{},{},{},{},{}",
+                report.day,
+                part.part,
+                part.variant,
+                part.answer,
+                part.duration.as_nanos()
+            );
+        }
+    }
+}
+
+/// Prints the trace of the dial of day 1 in the given format, the text format is a table.
+pub fn print_trace(steps: &[TraceStep], format: Format) {
+    match format {
+        Format::Text => {
+            let rows = steps
+                .iter()
+                .map(|step| {
+                    [
+                        step.rotation.to_string(),
+                        step.before.to_string(),
+                        step.after.to_string(),
+                        step.passes.to_string(),
+                        step.corrected.to_string(),
+                        if step.landed { "yes" } else { "no" }.to_string(),
+                        step.total_landings.to_string(),
+                        step.total_passes.to_string(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            print_table(
+                [
+                    "Rotation",
+                    "Before",
+                    "After",
+                    "Passes",
+                    "Corrected",
+                    "Landed",
+                    "Total landings",
+                    "Total passes",
+                ],
+                &rows,
+            );
+        }
+        Format::Json => {
+            let records = steps
+                .iter()
+                .map(|step| {
+                    format!(
+                        "  {{\"rotation\": \"{}\", \"before\": {}, \"after\": {}, \"passes\": {}, \"corrected\": {}, \"landed\": {}, \"total_landings\": {}, \"total_passes\": {}}}",
+                        step.rotation,
+                        step.before,
+                        step.after,
+                        step.passes,
+                        step.corrected,
+                        step.landed,
+                        step.total_landings,
+                        step.total_passes
+                    )
+                })
+                .collect::<Vec<_>>();
+            print_json_array(&records);
+        }
+        Format::Csv => {
+            println!("rotation,before,after,passes,corrected,landed,total_landings,total_passes");
+            for step in steps {
+                println!(
+                    "{},{},{},{},{},{},{},{}",
+                    step.rotation,
+                    step.before,
+                    step.after,
+                    step.passes,
+                    step.corrected,
+                    step.landed,
+                    step.total_landings,
+                    step.total_passes
+                );
+            }
+        }
+    }
+}
+
+/// Prints the answers of day 1 per start position of the dial in the given format, the text
+/// format is a table.
+pub fn print_start_counts(counts: &[StartCounts], format: Format) {
+    match format {
+        Format::Text => {
+            let rows = counts
+                .iter()
+                .map(|counts| {
+                    [
+                        counts.start.to_string(),
+                        counts.landings.to_string(),
+                        counts.passes.to_string(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            print_table(["Start", "Landings", "Passes"], &rows);
+        }
+        Format::Json => {
+            let records = counts
+                .iter()
+                .map(|counts| {
+                    format!(
+                        "  {{\"start\": {}, \"landings\": {}, \"passes\": {}}}",
+                        counts.start, counts.landings, counts.passes
+                    )
+                })
+                .collect::<Vec<_>>();
+            print_json_array(&records);
+        }
+        Format::Csv => {
+            println!("start,landings,passes");
+            for counts in counts {
+                println!("{},{},{}", counts.start, counts.landings, counts.passes);
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{BenchOptions, DayBench, PartBench, measure},
+    error::RunError,
+    generate::{GenerateOptions, Rng},
+    params::{Param, Params},
+    parse::ParseError,
+    pool::Pool,
+};
+
+/// The answer of a single part of a puzzle.
+pub type Answer = u64;
+
+/// Error of a part that cannot be solved for its input, e.g. because the input is parseable but
+/// violates an assumption of the puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Name of the variant of a part, if a day has only a single implementation for it.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Common interface of all days.
+///
+/// The parsed input is shared between both parts, so every part has to be side-effect free with
+/// respect to the input. Parts that need to modify the input have to clone it first.
+pub trait Solution {
+    /// The day of the advent calendar this solution solves.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared between the threads that solve the parts.
+    type Input: Sync;
+
+    /// The constants of the puzzle that can be changed on the command line.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input_data: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input, params: &Params) -> Result<Answer, SolveError>;
+
+    fn part2(input: &Self::Input, params: &Params) -> Result<Answer, SolveError>;
+
+    /// A random input in the format of the puzzle, `size` scales it, e.g. the number of lines.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// All implementations of the parts. The first variant of a part is the reference, all other
+    /// variants of the same part have to give the same answer.
+    fn variants() -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant::new(1, DEFAULT_VARIANT, Self::part1),
+            Variant::new(2, DEFAULT_VARIANT, Self::part2),
+        ]
+    }
+}
+
+/// A named implementation of a part.
+pub struct Variant<I> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&I, &Params) -> Result<Answer, SolveError>,
+    /// Only run if it is selected by name, e.g. because it is too slow for real inputs.
+    pub opt_in: bool,
+}
+
+impl<I> Variant<I> {
+    pub fn new(
+        part: u8,
+        name: &'static str,
+        solve: fn(&I, &Params) -> Result<Answer, SolveError>,
+    ) -> Self {
+        Self {
+            part,
+            name,
+            solve,
+            opt_in: false,
+        }
+    }
+
+    /// A variant that is only run if it is selected by name.
+    pub fn opt_in(
+        part: u8,
+        name: &'static str,
+        solve: fn(&I, &Params) -> Result<Answer, SolveError>,
+    ) -> Self {
+        Self {
+            opt_in: true,
+            ..Self::new(part, name, solve)
+        }
+    }
+}
+
+/// Selects the parts and variants that are run. `None` selects all parts or variants, except the
+/// opt-in variants unless `opt_in` is set.
+#[derive(Debug, Clone, Default)]
+pub struct VariantFilter {
+    pub part: Option<u8>,
+    pub variant: Option<String>,
+    pub opt_in: bool,
+}
+
+impl VariantFilter {
+    pub fn matches(&self, part: u8, variant: &str) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+            && self
+                .variant
+                .as_deref()
+                .is_none_or(|selected| selected == variant)
+    }
+
+    /// Whether `variant` is run, opt-in variants only if they are selected by name or `opt_in` is
+    /// set.
+    pub fn runs<I>(&self, variant: &Variant<I>) -> bool {
+        self.matches(variant.part, variant.name)
+            && (!variant.opt_in || self.opt_in || self.variant.is_some())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub variant: &'static str,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+/// A variant that could not solve its part. The other variants of the day are still reported.
+#[derive(Debug, Clone)]
+pub struct PartError {
+    pub part: u8,
+    pub variant: &'static str,
+    pub err: SolveError,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartReport>,
+    pub errors: Vec<PartError>,
+}
+
+/// A variant that gave another answer than the reference variant of its part.
+#[derive(Debug, Clone)]
+pub struct Divergence<'a> {
+    pub reference: &'a PartReport,
+    pub diverged: &'a PartReport,
+}
+
+impl DayReport {
+    /// Compares the answers of all variants against the reference variant of their part.
+    pub fn divergences(&self) -> Vec<Divergence<'_>> {
+        self.parts
+            .iter()
+            .filter_map(|diverged| {
+                let reference = self
+                    .parts
+                    .iter()
+                    .find(|reference| reference.part == diverged.part)?;
+                (reference.answer != diverged.answer).then_some(Divergence {
+                    reference,
+                    diverged,
+                })
+            })
+            .collect()
+    }
+
+    /// The errors of the variants that could not solve their part.
+    pub fn solve_errors(&self) -> impl Iterator<Item = RunError> + '_ {
+        self.errors.iter().map(|error| RunError::Solve {
+            day: self.day,
+            part: error.part,
+            variant: error.variant,
+            err: error.err.clone(),
+        })
+    }
+}
+
+/// Object safe counterpart of [`Solution`], so that all days can be stored in a single registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+
+    /// The part and the name of every variant.
+    fn variant_names(&self) -> Vec<(u8, &'static str)>;
+
+    fn params(&self) -> &'static [Param];
+
+    /// Parses the input and solves the parts with all variants selected by `filter`. The variants
+    /// are solved on the threads of `pool`, the parts of the report keep the order of the variants.
+    /// Only a parse error fails the whole day, variants that cannot solve their part are reported
+    /// as errors of the day next to the answers of the other variants.
+    fn run(
+        &self,
+        input_data: &str,
+        filter: &VariantFilter,
+        params: &Params,
+        pool: &Pool,
+    ) -> Result<DayReport, RunError>;
+
+    /// Benchmarks parsing and all variants selected by `filter`. Everything is measured on the
+    /// calling thread, so that other threads do not distort the timings.
+    fn bench(
+        &self,
+        input_data: &str,
+        options: &BenchOptions,
+        filter: &VariantFilter,
+        params: &Params,
+    ) -> Result<DayBench, RunError>;
+
+    /// A random input of the given size, the same seed always gives the same input.
+    fn generate(&self, options: &GenerateOptions) -> String;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn variant_names(&self) -> Vec<(u8, &'static str)> {
+        S::variants()
+            .into_iter()
+            .map(|variant| (variant.part, variant.name))
+            .collect()
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn generate(&self, options: &GenerateOptions) -> String {
+        S::generate(&mut Rng::new(options.seed), options.size)
+    }
+
+    fn run(
+        &self,
+        input_data: &str,
+        filter: &VariantFilter,
+        params: &Params,
+        pool: &Pool,
+    ) -> Result<DayReport, RunError> {
+        let start_parse = Instant::now();
+        let input = S::parse(input_data).map_err(|err| RunError::Parse { day: S::DAY, err })?;
+        let parse_duration = start_parse.elapsed();
+
+        let variants = S::variants()
+            .into_iter()
+            .filter(|variant| filter.runs(variant))
+            .collect::<Vec<_>>();
+        let results = pool.map(&variants, |variant| {
+            let start = Instant::now();
+            let answer = (variant.solve)(&input, params).map_err(|err| PartError {
+                part: variant.part,
+                variant: variant.name,
+                err,
+            })?;
+            let duration = start.elapsed();
+            Ok(PartReport {
+                part: variant.part,
+                variant: variant.name,
+                answer,
+                duration,
+            })
+        });
+
+        let mut parts = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(part) => parts.push(part),
+                Err(error) => errors.push(error),
+            }
+        }
+        Ok(DayReport {
+            day: S::DAY,
+            parse_duration,
+            parts,
+            errors,
+        })
+    }
+
+    fn bench(
+        &self,
+        input_data: &str,
+        options: &BenchOptions,
+        filter: &VariantFilter,
+        params: &Params,
+    ) -> Result<DayBench, RunError> {
+        let input = S::parse(input_data).map_err(|err| RunError::Parse { day: S::DAY, err })?;
+        let parse = measure(options, || S::parse(input_data));
+
+        let parts = S::variants()
+            .into_iter()
+            .filter(|variant| filter.runs(variant))
+            .map(|variant| {
+                // solve once up front, so that failing variants are reported instead of measured
+                (variant.solve)(&input, params).map_err(|err| solve_error::<S>(&variant, err))?;
+                Ok(PartBench {
+                    part: variant.part,
+                    variant: variant.name,
+                    stats: measure(options, || (variant.solve)(&input, params)),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DayBench {
+            day: S::DAY,
+            parse,
+            parts,
+        })
+    }
+}
+
+fn solve_error<S: Solution>(variant: &Variant<S::Input>, err: SolveError) -> RunError {
+    RunError::Solve {
+        day: S::DAY,
+        part: variant.part,
+        variant: variant.name,
+        err,
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::SystemTime;
+
+use crate::{
+    report::print_table,
+    solution::{Answer, DayReport},
+};
+
+/// Modification time and size of a watched file, `None` if it does not exist. A file counts as
+/// changed whenever its stamp changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStamp(Option<(SystemTime, u64)>);
+
+impl FileStamp {
+    pub fn of(path: &str) -> Self {
+        let metadata = std::fs::metadata(path).ok();
+        Self(metadata.and_then(|metadata| Some((metadata.modified().ok()?, metadata.len()))))
+    }
+}
+
+/// An answer that differs from the previous run. An answer is `None` if the part was not solved in
+/// that run, e.g. because its input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerChange {
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub previous: Option<Answer>,
+    pub current: Option<Answer>,
+}
+
+/// All answers of `current` that differ from `previous`, followed by the answers of `previous`
+/// that are missing in `current`.
+pub fn answer_changes(previous: &[DayReport], current: &[DayReport]) -> Vec<AnswerChange> {
+    let answers = |reports: &[DayReport]| {
+        reports
+            .iter()
+            .flat_map(|report| {
+                report
+                    .parts
+                    .iter()
+                    .map(|part| ((report.day, part.part, part.variant), part.answer))
+            })
+            .collect::<Vec<_>>()
+    };
+    let previous = answers(previous);
+    let current = answers(current);
+    let find = |answers: &[((u8, u8, &'static str), Answer)], key| {
+        answers
+            .iter()
+            .find(|(other_key, _)| *other_key == key)
+            .map(|&(_, answer)| answer)
+    };
+
+    let changed = current
+        .iter()
+        .map(|&(key, answer)| (key, find(&previous, key), Some(answer)));
+    let removed = previous
+        .iter()
+        .filter(|&&(key, _)| find(&current, key).is_none())
+        .map(|&(key, answer)| (key, Some(answer), None));
+
+    changed
+        .chain(removed)
+        .filter(|(_, previous, current)| previous != current)
+        .map(|((day, part, variant), previous, current)| AnswerChange {
+            day,
+            part,
+            variant,
+            previous,
+            current,
+        })
+        .collect()
+}
+
+/// Prints the answers that changed since the previous run as a table.
+pub fn print_answer_changes(changes: &[AnswerChange]) {
+    if changes.is_empty() {
+        println!("No answer changed.");
+        return;
+    }
+
+    let format_answer =
+        |answer: Option<Answer>| answer.map_or(String::from("-"), |a| a.to_string());
+    let rows = changes
+        .iter()
+        .map(|change| {
+            [
+                change.day.to_string(),
+                change.part.to_string(),
+                change.variant.to_string(),
+                format_answer(change.previous),
+                format_answer(change.current),
+            ]
+        })
+        .collect::<Vec<_>>();
+    print_table(["Day", "Task", "Variant", "Previous", "Answer"], &rows);
+}
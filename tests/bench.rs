@@ -0,0 +1,50 @@
+//! Checks that benchmark baselines survive saving and loading.
+
+use std::time::Duration;
+
+use sol::bench::{Baseline, DayBench, PartBench, Stats};
+
+#[test]
+fn input_paths_with_separators_are_quoted() {
+    let stats = |nanos| Stats {
+        min: Duration::from_nanos(nanos),
+        median: Duration::from_nanos(nanos),
+        mean: Duration::from_nanos(nanos),
+        stddev: Duration::ZERO,
+    };
+    let bench = DayBench {
+        day: 3,
+        parse: stats(10),
+        parts: vec![PartBench {
+            part: 1,
+            variant: "default",
+            stats: stats(20),
+        }],
+    };
+    let input_file = "/tmp/odd, \"quoted\"\ninput.txt";
+    let mut baseline = Baseline::default();
+    baseline.update(input_file, &[bench]);
+
+    let path = std::env::temp_dir().join(format!("baseline-{}.csv", std::process::id()));
+    let path = path.to_str().unwrap();
+    baseline.save(path).unwrap();
+    let loaded = Baseline::load(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    let loaded = loaded.expect("the saved baseline can be parsed");
+    assert_eq!(
+        loaded.get(3, input_file, 1, "default"),
+        Some(Duration::from_nanos(20))
+    );
+    assert_eq!(
+        loaded.get(3, input_file, 0, "parse"),
+        Some(Duration::from_nanos(10))
+    );
+}
+
+#[test]
+fn unclosed_quotes_are_rejected() {
+    assert!(
+        Baseline::parse("day,input,task,variant,median_ns\n3,\"input.txt,1,default,20\n").is_none()
+    );
+}
@@ -0,0 +1,96 @@
+//! Solves every checked-in input that has expected answers in `dayNN/answers.toml` with every
+//! variant of the parts, including the opt-in ones, and compares the answers.
+
+use sol::{
+    SOLUTIONS,
+    answers::{CheckResult, ExpectedAnswers},
+    pool::Pool,
+    solution::VariantFilter,
+};
+
+fn check_examples(day: u8) {
+    let solution = SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .unwrap_or_else(|| panic!("day {day} is not solved"));
+    let expected = ExpectedAnswers::load(day)
+        .unwrap_or_else(|err| panic!("cannot read {}: {err}", ExpectedAnswers::path(day)))
+        .unwrap_or_else(|| panic!("invalid expected answers in {}", ExpectedAnswers::path(day)));
+
+    let input_files = expected.input_files();
+    assert!(!input_files.is_empty(), "day {day} has no expected answers");
+
+    for input_file in input_files {
+        let path = format!("day{day:02}/{input_file}");
+        let input_data = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("cannot read {path}: {err}"));
+        let params = expected.params(input_file);
+
+        // inputs may only contain what one of the parts needs, so only parts with answers are run
+        for part in expected.parts(input_file) {
+            let filter = VariantFilter {
+                part: Some(part),
+                variant: None,
+                opt_in: true,
+            };
+            let report = solution
+                .run(&input_data, &filter, &params, &Pool::default())
+                .unwrap_or_else(|err| panic!("{path}: {err}"));
+
+            if let Some(err) = report.solve_errors().next() {
+                panic!("{path}: {err}");
+            }
+            assert!(
+                !report.parts.is_empty(),
+                "{path}: day {day} has no variant of part {part}"
+            );
+            for part_report in &report.parts {
+                assert_eq!(
+                    expected.check(input_file, part_report),
+                    CheckResult::Pass,
+                    "{path}: part {part} (variant \"{}\") answered {}",
+                    part_report.variant,
+                    part_report.answer
+                );
+            }
+        }
+    }
+}
+
+macro_rules! example_tests {
+    ($($name:ident: $day:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_examples($day);
+            }
+        )*
+
+        #[test]
+        fn every_day_is_tested() {
+            let tested = [$($day),*];
+            for solution in SOLUTIONS {
+                assert!(
+                    tested.contains(&solution.day()),
+                    "day {} has no example test",
+                    solution.day()
+                );
+            }
+        }
+    };
+}
+
+example_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+}
@@ -0,0 +1,51 @@
+//! Checks that the generated inputs of every day can be parsed and solved.
+
+use sol::{
+    SOLUTIONS, generate::GenerateOptions, params::Params, pool::Pool, solution::VariantFilter,
+};
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for solution in SOLUTIONS {
+        for size in [1, 2, 3, 10, 25] {
+            for seed in 0..3 {
+                let input_data = solution.generate(&GenerateOptions { size, seed });
+                let err = match solution.run(
+                    &input_data,
+                    &VariantFilter::default(),
+                    &Params::default(),
+                    &Pool::default(),
+                ) {
+                    Ok(report) => {
+                        assert!(
+                            report.divergences().is_empty(),
+                            "day {} size {size} seed {seed}: variants diverge\n{input_data}",
+                            solution.day()
+                        );
+                        report.solve_errors().next()
+                    }
+                    Err(err) => Some(err),
+                };
+                if let Some(err) = err {
+                    panic!(
+                        "day {} size {size} seed {seed}: {err}\n{input_data}",
+                        solution.day()
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn same_seed_gives_same_input() {
+    for solution in SOLUTIONS {
+        let options = GenerateOptions { size: 10, seed: 42 };
+        assert_eq!(
+            solution.generate(&options),
+            solution.generate(&options),
+            "day {}",
+            solution.day()
+        );
+    }
+}
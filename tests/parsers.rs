@@ -0,0 +1,176 @@
+//! Feeds malformed inputs to the parsers of all days, which have to return an error instead of
+//! panicking. The inputs are either random text over the characters of the input format or
+//! generated inputs with random edits.
+
+use proptest::prelude::*;
+
+use sol::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11, day12::Day12,
+    generate::Rng, solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(char),
+    Remove,
+    Replace(char),
+}
+
+/// A malformed input built from the characters of `alphabet`.
+fn malformed_input(
+    generate: fn(&mut Rng, usize) -> String,
+    alphabet: &'static str,
+) -> impl Strategy<Value = String> {
+    let chars = alphabet.chars().chain(['\n', ' ']).collect::<Vec<_>>();
+    let char_ = prop::sample::select(chars);
+    let edit = prop_oneof![
+        char_.clone().prop_map(Edit::Insert),
+        Just(Edit::Remove),
+        char_.clone().prop_map(Edit::Replace),
+    ];
+
+    let random = prop::collection::vec(char_, 0..40).prop_map(String::from_iter);
+    let edited = (
+        any::<u64>(),
+        1..4usize,
+        prop::collection::vec((any::<prop::sample::Index>(), edit), 1..6),
+    )
+        .prop_map(move |(seed, size, edits)| {
+            let mut input = generate(&mut Rng::new(seed), size)
+                .chars()
+                .collect::<Vec<_>>();
+            for (idx, edit) in edits {
+                let idx = idx.index(input.len() + 1);
+                match edit {
+                    Edit::Insert(c) => input.insert(idx, c),
+                    Edit::Remove if idx < input.len() => _ = input.remove(idx),
+                    Edit::Replace(c) if idx < input.len() => input[idx] = c,
+                    Edit::Remove | Edit::Replace(_) => {}
+                }
+            }
+            String::from_iter(input)
+        });
+
+    prop_oneof![random, edited]
+}
+
+macro_rules! parser_tests {
+    ($($name:ident: $solution:ty, $alphabet:literal;)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(input_data in malformed_input(<$solution>::generate, $alphabet)) {
+                    let _ = <$solution>::parse(&input_data);
+                }
+            )*
+        }
+    };
+}
+
+parser_tests! {
+    day01_parser_does_not_panic: Day01, "LR=0123456789x()#";
+    day02_parser_does_not_panic: Day02, "0123456789-,";
+    day03_parser_does_not_panic: Day03, "0123456789é";
+    day04_parser_does_not_panic: Day04, ".@é";
+    day05_parser_does_not_panic: Day05, "0123456789-";
+    day06_parser_does_not_panic: Day06, "0123456789+*";
+    day07_parser_does_not_panic: Day07, ".S^é";
+    day08_parser_does_not_panic: Day08, "0123456789,";
+    day09_parser_does_not_panic: Day09, "0123456789,";
+    day10_parser_does_not_panic: Day10, "0123456789.#[](){},";
+    day11_parser_does_not_panic: Day11, "abcdefghijklmnopqrstuvwxyz:";
+    day12_parser_does_not_panic: Day12, "0123456789#.x:";
+}
+
+#[test]
+fn day01_repeat_blocks_are_expanded() {
+    let expanded = Day01::parse("=5\n2x(R1 2x(L2)) # comment\n\nL3").unwrap();
+    let plain = Day01::parse("=5\nR1\nL2\nL2\nR1\nL2\nL2\nL3").unwrap();
+    assert_eq!(expanded, plain);
+}
+
+#[test]
+fn day01_malformed_repeat_blocks() {
+    assert!(Day01::parse("2(R1)").is_err());
+    assert!(Day01::parse("2x(R1").is_err());
+    assert!(Day01::parse("R1)").is_err());
+    assert!(Day01::parse("1000x(1000x(2x(R1)))").is_err());
+}
+
+#[test]
+fn day01_repeat_blocks_are_limited_in_total() {
+    let err = Day01::parse(&"999999x(R1)\n".repeat(1000)).unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn day01_deeply_nested_repeat_blocks() {
+    let nested = |depth| format!("{}R1{}", "1x(".repeat(depth), ")".repeat(depth));
+    let max_depth = sol::day01::MAX_REPEAT_DEPTH;
+    assert_eq!(Day01::parse(&nested(max_depth)).unwrap().len(), 1);
+    assert!(Day01::parse(&nested(max_depth + 1)).is_err());
+    assert!(Day01::parse(&nested(1_000_000)).is_err());
+}
+
+// inputs that made the parsers panic
+
+#[test]
+fn day04_empty_first_line() {
+    assert!(Day04::parse("\n").is_err());
+}
+
+#[test]
+fn day06_operators_before_numbers() {
+    assert!(Day06::parse("+\n").is_err());
+}
+
+#[test]
+fn day06_more_operators_than_problems() {
+    assert!(Day06::parse("1\n+ *").is_err());
+}
+
+#[test]
+fn day06_more_problems_than_operators() {
+    assert!(sol::day06::parse_input2("1 2\n+").is_err());
+}
+
+#[test]
+fn day07_ragged_lines() {
+    assert!(Day07::parse("..S..\n...\n..^..").is_err());
+}
+
+#[test]
+fn day08_fourth_coordinate() {
+    let err = Day08::parse("1,2,3\n1,2,3,4").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 7));
+    assert_eq!(err.found, "4");
+}
+
+#[test]
+fn day10_light_index_out_of_range() {
+    assert!(Day10::parse("[.#] (0,2) {1,2}").is_err());
+}
+
+#[test]
+fn day10_sections_out_of_order() {
+    assert!(Day10::parse("{1,2} (0) [.#]").is_err());
+    assert!(Day10::parse("[.#] ) (0 {1,2}").is_err());
+}
+
+// the parsed inputs are part of the library API
+
+#[test]
+fn day04_grid_dimensions() {
+    let grid = Day04::parse("..@\n@..").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+}
+
+#[test]
+fn day11_paths_between_devices() {
+    let input = Day11::parse("you: a b\na: out\nb: out").unwrap();
+    let you = sol::day11::device_idx("you", &input).unwrap();
+    let out = sol::day11::device_idx("out", &input).unwrap();
+    assert_eq!(input.devices[you].len(), 2);
+    assert_eq!(sol::day11::find_paths_memo(you, out, &input), 2);
+}
@@ -0,0 +1,85 @@
+//! Checks that the thread pool keeps the order of the results and its limit of threads.
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use sol::{
+    SOLUTIONS, generate::GenerateOptions, params::Params, pool::Pool, solution::VariantFilter,
+};
+
+#[test]
+fn results_keep_the_order_of_the_items() {
+    let items = (0..50).collect::<Vec<u64>>();
+    for jobs in [1, 2, 8, 100] {
+        let results = Pool::new(jobs).map(&items, |item| item * item);
+        assert_eq!(
+            results,
+            items.iter().map(|item| item * item).collect::<Vec<_>>(),
+            "{jobs} jobs"
+        );
+    }
+}
+
+#[test]
+fn nested_maps_share_the_limit_of_threads() {
+    let jobs = 3;
+    let pool = Pool::new(jobs);
+    let running = AtomicUsize::new(0);
+    let max_running = AtomicUsize::new(0);
+
+    let outer = (0..4).collect::<Vec<_>>();
+    let inner = (0..4).collect::<Vec<_>>();
+    pool.map(&outer, |_| {
+        pool.map(&inner, |_| {
+            let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now_running, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+        })
+    });
+
+    assert!(max_running.load(Ordering::SeqCst) <= jobs);
+}
+
+#[test]
+fn parallel_runs_give_the_same_answers() {
+    let answers = |report: sol::solution::DayReport| {
+        assert!(
+            report.divergences().is_empty(),
+            "day {}: variants diverge",
+            report.day
+        );
+        if let Some(err) = report.solve_errors().next() {
+            panic!("day {}: {err}", report.day);
+        }
+        report
+            .parts
+            .iter()
+            .map(|part| (part.part, part.variant, part.answer))
+            .collect::<Vec<_>>()
+    };
+
+    let pool = Pool::new(4);
+    for solution in SOLUTIONS {
+        let input_data = solution.generate(&GenerateOptions::default());
+        let run = |pool| {
+            solution
+                .run(
+                    &input_data,
+                    &VariantFilter::default(),
+                    &Params::default(),
+                    pool,
+                )
+                .unwrap_or_else(|err| panic!("day {}: {err}", solution.day()))
+        };
+        assert_eq!(
+            answers(run(&pool)),
+            answers(run(&Pool::default())),
+            "day {}",
+            solution.day()
+        );
+    }
+}
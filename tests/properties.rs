@@ -0,0 +1,266 @@
+//! Property tests that compare the fast implementations of the days against their slow but obvious
+//! counterparts on random inputs.
+
+use proptest::prelude::*;
+
+use sol::{day01, day02, day04, day05};
+
+fn direction() -> impl Strategy<Value = day01::Direction> {
+    prop_oneof![Just(day01::Direction::Left), Just(day01::Direction::Right)]
+}
+
+/// Turns and absolute moves, which may set the dial to positions far beyond its size.
+fn rotation() -> impl Strategy<Value = day01::Rotation> {
+    prop_oneof![
+        8 => (direction(), 0..1000u64)
+            .prop_map(|(dir, distance)| day01::Rotation::Turn { dir, distance }),
+        1 => prop_oneof![0..1000u64, any::<u64>()]
+            .prop_map(|position| day01::Rotation::Absolute { position }),
+    ]
+}
+
+/// A dial of any size with a start position and up to 5 targets, which may lie outside of the dial.
+fn dial() -> impl Strategy<Value = day01::Dial> {
+    (1..200i64).prop_flat_map(|size| {
+        (
+            -size..2 * size,
+            prop::collection::vec(-size..2 * size, 0..5),
+        )
+            .prop_map(move |(start, targets)| day01::Dial::new(size, start, targets))
+    })
+}
+
+/// ID ranges with IDs up to the given number of digits, IDs start at 1 as in the puzzle.
+fn id_ranges(max_digits: u32) -> impl Strategy<Value = Vec<day02::IDRange>> {
+    let max_id = 10u64.pow(max_digits) - 1;
+    prop::collection::vec(
+        (1..=max_id, 0..2000u64).prop_map(move |(start, len)| day02::IDRange {
+            start,
+            end: (start + len).min(max_id),
+        }),
+        0..4,
+    )
+}
+
+/// ID ranges around an ID with 20 digits that repeats a block of 10 digits, up to the largest ID.
+fn ranges_near_max_id() -> impl Strategy<Value = Vec<day02::IDRange>> {
+    prop::collection::vec(
+        (1_000_000_000..=1_844_674_407u64, 0..1000u64, 0..1000u64).prop_map(
+            |(block, before, after)| {
+                let id = block * 10_000_000_001;
+                day02::IDRange {
+                    start: id - before,
+                    end: id.saturating_add(after),
+                }
+            },
+        ),
+        1..3,
+    )
+}
+
+fn fresh_db() -> impl Strategy<Value = day05::DB> {
+    let range = (0..1000u64, 0..100u64).prop_map(|(start, len)| day05::IDRange {
+        start,
+        end: start + len,
+    });
+    (
+        prop::collection::vec(range, 0..20),
+        prop::collection::vec(0..1200u64, 0..50),
+    )
+        .prop_map(|(fresh_r, ids)| day05::DB { fresh_r, ids })
+}
+
+/// A grid of rolls in the input format of day 4.
+fn roll_grid() -> impl Strategy<Value = String> {
+    (1..20usize, 1..20usize).prop_flat_map(|(width, height)| {
+        prop::collection::vec(prop::bool::weighted(0.6), width * height).prop_map(move |cells| {
+            cells
+                .chunks(width)
+                .map(|row| {
+                    row.iter()
+                        .map(|&roll| if roll { '@' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    })
+}
+
+proptest! {
+    #[test]
+    fn day01_counting_passes_matches_simulation(
+        rotations in prop::collection::vec(rotation(), 0..50),
+        dial in dial(),
+    ) {
+        let simulated = dial.simulate(&rotations);
+        prop_assert_eq!(dial.count_passes(&rotations).ok(), Some(simulated));
+        prop_assert_eq!(dial.count_passes_mirrored(&rotations).ok(), Some(simulated));
+    }
+
+    #[test]
+    fn day01_full_turns_pass_every_target_once(
+        dir in direction(),
+        distance in 0..1000u64,
+        turns in 0..u64::MAX / 1000,
+        dial in dial(),
+    ) {
+        let turns = turns / dial.size() as u64;
+        let rotation = day01::Rotation::Turn { dir, distance };
+        let long_rotation = day01::Rotation::Turn {
+            dir,
+            distance: distance + turns * dial.size() as u64,
+        };
+        prop_assert_eq!(
+            dial.count_landings(&[long_rotation]),
+            dial.count_landings(&[rotation])
+        );
+        prop_assert_eq!(
+            dial.count_passes(&[long_rotation]).ok(),
+            Some(dial.count_passes(&[rotation]).unwrap() + turns * dial.targets().len() as u64)
+        );
+        prop_assert_eq!(
+            dial.count_passes_mirrored(&[long_rotation]).ok(),
+            dial.count_passes(&[long_rotation]).ok()
+        );
+    }
+
+    #[test]
+    fn day01_absolute_moves_wrap_onto_the_dial(position in any::<u64>(), dial in dial()) {
+        let wrapped = (position % dial.size() as u64) as i64;
+        prop_assert_eq!(
+            dial.count_landings(&[day01::Rotation::Absolute { position }]),
+            dial.targets().contains(&wrapped) as u64
+        );
+    }
+
+    #[test]
+    fn day01_counts_by_start_match_counts_of_every_start(
+        rotations in prop::collection::vec(rotation(), 0..20),
+        dial in dial(),
+    ) {
+        for counts in dial.counts_by_start(&rotations).unwrap() {
+            let started = day01::Dial::new(dial.size(), counts.start, dial.targets().to_vec());
+            prop_assert_eq!(counts.landings, started.count_landings(&rotations));
+            prop_assert_eq!(Some(counts.passes), started.count_passes(&rotations).ok());
+        }
+    }
+
+    #[test]
+    fn day01_trace_totals_match_counts(
+        rotations in prop::collection::vec(rotation(), 1..50),
+        dial in dial(),
+    ) {
+        let trace = dial.trace(&rotations).unwrap();
+        let last = trace.last().unwrap();
+        prop_assert_eq!(last.total_landings, dial.count_landings(&rotations));
+        prop_assert_eq!(Some(last.total_passes), dial.count_passes(&rotations).ok());
+    }
+
+    #[test]
+    fn day02_task1_digits_match_strings(ranges in id_ranges(8)) {
+        prop_assert_eq!(
+            day02::find_invalid_ids_task_1(&ranges).collect::<Vec<_>>(),
+            day02::find_invalid_ids_task_1_slow(&ranges).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn day02_task2_digits_match_strings(ranges in id_ranges(8)) {
+        prop_assert_eq!(
+            day02::find_invalid_ids_task2(&ranges).collect::<Vec<_>>(),
+            day02::find_invalid_ids_task2_slow(&ranges).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn day02_arithmetic_matches_strings(ranges in id_ranges(8)) {
+        prop_assert_eq!(
+            day02::find_invalid_ids_task_1_arithmetic(&ranges).collect::<Vec<_>>(),
+            day02::find_invalid_ids_task_1_slow(&ranges).collect::<Vec<_>>()
+        );
+        prop_assert_eq!(
+            day02::sum_invalid_ids_task_1_arithmetic(&ranges),
+            day02::checked_sum(day02::find_invalid_ids_task_1_slow(&ranges))
+        );
+        prop_assert_eq!(
+            day02::sum_invalid_ids_task2_arithmetic(&ranges),
+            day02::checked_sum(day02::find_invalid_ids_task2_slow(&ranges))
+        );
+    }
+
+    #[test]
+    fn day02_arithmetic_matches_strings_near_the_largest_id(ranges in ranges_near_max_id()) {
+        prop_assert_eq!(
+            day02::find_invalid_ids_task_1_arithmetic(&ranges).collect::<Vec<_>>(),
+            day02::find_invalid_ids_task_1_slow(&ranges).collect::<Vec<_>>()
+        );
+        prop_assert_eq!(
+            day02::sum_invalid_ids_task_1_arithmetic(&ranges),
+            day02::checked_sum(day02::find_invalid_ids_task_1_slow(&ranges))
+        );
+        prop_assert_eq!(
+            day02::sum_invalid_ids_task2_arithmetic(&ranges),
+            day02::checked_sum(day02::find_invalid_ids_task2_slow(&ranges))
+        );
+    }
+
+    #[test]
+    fn day02_arithmetic_sums_of_wide_ranges_add_up(
+        start in 1..=u64::MAX,
+        len in any::<u64>(),
+        split in any::<u64>(),
+    ) {
+        // too many IDs to test each, but the sum of a range is the sum of its halves
+        let end = start.saturating_add(len);
+        prop_assume!(start < end);
+        let split = start + split % (end - start);
+        let whole = day02::IDRange { start, end };
+        let lower = day02::IDRange { start, end: split };
+        let upper = day02::IDRange { start: split + 1, end };
+        for repeats in 2..=20 {
+            prop_assert_eq!(
+                day02::sum_repeated_ids(&whole, repeats),
+                day02::sum_repeated_ids(&lower, repeats) + day02::sum_repeated_ids(&upper, repeats)
+            );
+        }
+    }
+
+    #[test]
+    fn day05_merged_ranges_match_naive(db in fresh_db()) {
+        let naive = day05::find_fresh_ids_naive(&db).collect::<Vec<_>>();
+        let mut merged_db = db.clone();
+        prop_assert_eq!(day05::find_fresh_ids_smart(&mut merged_db).collect::<Vec<_>>(), naive);
+
+        let mut all_fresh = db
+            .fresh_r
+            .iter()
+            .flat_map(|range| range.start..=range.end)
+            .collect::<Vec<_>>();
+        all_fresh.sort_unstable();
+        all_fresh.dedup();
+        let mut merged_db = db.clone();
+        prop_assert_eq!(
+            day05::find_fresh_id_ranges(&mut merged_db).collect::<Vec<_>>(),
+            all_fresh
+        );
+    }
+
+    #[test]
+    fn day04_removers_agree(grid_str in roll_grid(), neighbour_limit in 0..9usize) {
+        let grid = day04::parse_input(&grid_str).unwrap();
+        let naive = day04::solve_task2_naive(grid.clone(), neighbour_limit);
+        prop_assert_eq!(day04::solve_task2_smarter(grid.clone(), neighbour_limit), naive);
+        prop_assert_eq!(day04::solve_task2_smartest(grid, neighbour_limit), naive);
+    }
+}
+
+#[test]
+fn day02_arithmetic_sums_beyond_the_largest_id_are_rejected() {
+    let all_ids = [day02::IDRange {
+        start: 1,
+        end: u64::MAX,
+    }];
+    assert_eq!(day02::sum_invalid_ids_task_1_arithmetic(&all_ids), None);
+    assert_eq!(day02::sum_invalid_ids_task2_arithmetic(&all_ids), None);
+}
@@ -0,0 +1,34 @@
+//! Inputs that parse, but whose answers cannot be computed, have to fail with an error instead of
+//! panicking or wrapping around.
+
+use sol::{day03::Day03, day12::Day12, params::Params, solution::Solution};
+
+#[test]
+fn day03_joltage_sum_overflow() {
+    let input = Day03::parse(&["9".repeat(19), "9".repeat(19)].join("\n")).unwrap();
+    let mut params = Params::default();
+    params.set("batteries2", "19");
+    assert!(Day03::part2(&input, &params).is_err());
+
+    params.set("batteries2", "18");
+    assert_eq!(
+        Day03::part2(&input, &params).unwrap(),
+        2 * (10u64.pow(18) - 1)
+    );
+}
+
+#[test]
+fn day12_huge_regions() {
+    let shapes = "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##\n\n";
+    for region in [
+        "99999999999x99999999999: 1 0",
+        "2x2: 18446744073709551615 1",
+        "5000x5000: 3000000 0",
+    ] {
+        let input = Day12::parse(&format!("{shapes}{region}")).unwrap();
+        assert!(
+            Day12::part1(&input, &Params::default()).is_err(),
+            "{region}"
+        );
+    }
+}
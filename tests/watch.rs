@@ -0,0 +1,59 @@
+//! Checks which answers count as changed between two runs in watch mode.
+
+use std::time::Duration;
+
+use sol::{
+    solution::{DayReport, PartReport},
+    watch::{AnswerChange, answer_changes},
+};
+
+fn report(day: u8, answers: &[(u8, &'static str, u64)]) -> DayReport {
+    DayReport {
+        day,
+        parse_duration: Duration::ZERO,
+        parts: answers
+            .iter()
+            .map(|&(part, variant, answer)| PartReport {
+                part,
+                variant,
+                answer,
+                duration: Duration::ZERO,
+            })
+            .collect(),
+        errors: Vec::new(),
+    }
+}
+
+#[test]
+fn unchanged_answers_are_not_reported() {
+    let reports = [report(1, &[(1, "default", 3), (2, "default", 6)])];
+    assert_eq!(answer_changes(&reports, &reports), []);
+}
+
+#[test]
+fn changed_new_and_missing_answers_are_reported() {
+    let previous = [
+        report(1, &[(1, "default", 3), (2, "default", 6)]),
+        report(2, &[(1, "default", 7)]),
+    ];
+    let current = [report(
+        1,
+        &[(1, "default", 4), (2, "default", 6), (2, "fast", 6)],
+    )];
+
+    let change = |day, part, variant, previous, current| AnswerChange {
+        day,
+        part,
+        variant,
+        previous,
+        current,
+    };
+    assert_eq!(
+        answer_changes(&previous, &current),
+        [
+            change(1, 1, "default", Some(3), Some(4)),
+            change(1, 2, "fast", None, Some(6)),
+            change(2, 1, "default", Some(7), None),
+        ]
+    );
+}